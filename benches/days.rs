@@ -0,0 +1,112 @@
+use std::hint::black_box;
+
+use advent_22::{
+    days,
+    days::{day01, day02, day03, day04, day05, day06, day07, day08},
+    days::{day09, day10, day11, day12, day13, day14, day15},
+    generate, input, AocError, Solution,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn real_input(day: u8) -> String {
+    input::read_to_string(input::default_path(day)).unwrap()
+}
+
+/// Benchmarks parsing and both parts of a day against its real input,
+/// skipping any part that hasn't been solved
+fn bench_day<S: Solution>(c: &mut Criterion, sample_size: usize) {
+    let input = real_input(S::DAY);
+    let parsed = S::parse(&input).unwrap();
+    let params = S::Params::default();
+
+    let mut group = c.benchmark_group(format!("day-{}", S::DAY));
+    group.sample_size(sample_size);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    if !matches!(
+        S::part_one(&parsed, &params),
+        Err(AocError::Unsolved { .. })
+    ) {
+        group.bench_function("part-one", |b| {
+            b.iter(|| S::part_one(black_box(&parsed), &params))
+        });
+    }
+    if !matches!(
+        S::part_two(&parsed, &params),
+        Err(AocError::Unsolved { .. })
+    ) {
+        group.bench_function("part-two", |b| {
+            b.iter(|| S::part_two(black_box(&parsed), &params))
+        });
+    }
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<day01::Day01>(c, 100);
+    bench_day::<day02::Day02>(c, 100);
+    bench_day::<day03::Day03>(c, 100);
+    bench_day::<day04::Day04>(c, 100);
+    bench_day::<day05::Day05>(c, 100);
+    bench_day::<day06::Day06>(c, 100);
+    bench_day::<day07::Day07>(c, 100);
+    bench_day::<day08::Day08>(c, 100);
+    bench_day::<day09::Day09>(c, 100);
+    bench_day::<day10::Day10>(c, 100);
+    bench_day::<day11::Day11>(c, 100);
+    bench_day::<day12::Day12>(c, 10);
+    bench_day::<day13::Day13>(c, 100);
+    bench_day::<day14::Day14>(c, 10);
+    bench_day::<day15::Day15>(c, 10);
+}
+
+fn day_6_variants(c: &mut Criterion) {
+    let input = real_input(6);
+
+    let mut group = c.benchmark_group("day-6/part-two");
+    group.bench_function("windows", |b| b.iter(|| day06::part_two(black_box(&input))));
+    group.bench_function("hashmap", |b| {
+        b.iter(|| day06::part_two_hashmap(black_box(&input)))
+    });
+    group.bench_function("hashset", |b| {
+        b.iter(|| day06::part_two_hashset(black_box(&input)))
+    });
+    group.finish();
+}
+
+/// Finding the top three elves by parsing every total then using a heap of
+/// all of them, against streaming through with a heap of just three
+fn day_1_top_n(c: &mut Criterion) {
+    let input = generate::input(1, 100_000, 0).unwrap();
+
+    let mut group = c.benchmark_group("day-1/top-3");
+    group.sample_size(20);
+    group.bench_function("buffered", |b| {
+        b.iter(|| {
+            let totals = day01::Day01::parse(black_box(&input)).unwrap();
+            day01::top_n_calories(&totals, 3)
+        })
+    });
+    group.bench_function("streaming", |b| {
+        b.iter(|| day01::top_n_streaming(black_box(input.as_bytes()), 3).unwrap())
+    });
+    group.finish();
+}
+
+/// How the slower grid days scale with generated inputs of growing size
+fn scaling(c: &mut Criterion) {
+    for day in [8, 12, 14] {
+        let mut group = c.benchmark_group(format!("day-{}/scaling", day));
+        group.sample_size(10);
+        let solve = days::get(day).unwrap().part_two;
+        for size in [25, 50, 100] {
+            let input = generate::input(day, size, 0).unwrap();
+            group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+                b.iter(|| solve(black_box(input)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days, day_1_top_n, day_6_variants, scaling);
+criterion_main!(benches);
@@ -0,0 +1,17 @@
+//! Fuzz targets feeding arbitrary input through each day, run with
+//! `cargo +nightly fuzz run day_11`. Adding `../input` after the target name
+//! seeds the corpus with the real and example inputs. Inputs that once
+//! crashed a day are checked in under `corpus/`, so every run replays them.
+
+use advent_22::days;
+
+/// Parses and solves both parts of `day` with `data` as the input
+///
+/// Errors are ignored, as rejecting bad input is expected, so only panics,
+/// overflows and hangs are reported
+pub fn solve(day: u8, data: &[u8]) {
+    let input = String::from_utf8_lossy(data);
+    let day = days::get(day).expect("only solved days have fuzz targets");
+    let _ = (day.part_one)(&input);
+    let _ = (day.part_two)(&input);
+}
@@ -0,0 +1,359 @@
+use std::{
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_22::{
+    days::{self, Answer, Day},
+    generate, input, pool, scaffold, AocError,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::json;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for one day, or every day
+    Run(RunArgs),
+    /// Generate the solution module, binary and input files for a new day
+    New(NewArgs),
+    /// Print a random puzzle input for a day
+    Gen(GenArgs),
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One sentence per answer
+    Human,
+    /// One JSON record per line, with times in seconds and memory in bytes.
+    /// Each record's "type" is "answer", "day" for a day's timing with
+    /// --all, or "summary" for the run's total and critical path.
+    Json,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Run every day, printing how long each took and the critical path,
+    /// the longest single part, which bounds the run however many jobs
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Number of parts to solve at once, defaulting to one per CPU
+    #[arg(long)]
+    jobs: Option<NonZeroUsize>,
+
+    /// Only run the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input to use instead of input/day-N.txt, or - for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+
+    /// How to print answers
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to generate
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Roughly how many lines, records or squares across the input has
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+
+    /// Seed for the random number generator, so inputs can be reproduced
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+/// Size in the largest binary unit that keeps it at least 1
+fn bytes(count: usize) -> String {
+    let mut size = count as f64;
+    for unit in ["B", "KiB", "MiB"] {
+        if size < 1024.0 {
+            return format!("{:.1} {}", size, unit);
+        }
+        size /= 1024.0;
+    }
+    format!("{:.1} GiB", size)
+}
+
+fn print_answer(format: Format, day: u8, part: u8, answer: Option<&Answer>) {
+    match (format, answer) {
+        (Format::Human, Some(answer)) => {
+            println!("Day {} part {}: {}", day, part, answer.text);
+            if let Some(memory) = answer.memory {
+                println!(
+                    "  peak heap {}, {} allocations, {} allocated",
+                    bytes(memory.peak),
+                    memory.allocations,
+                    bytes(memory.allocated)
+                );
+            }
+        }
+        (Format::Human, None) => println!("Day {} part {}: not implemented", day, part),
+        (Format::Json, Some(answer)) => {
+            let mut record = json!({
+                "type": "answer",
+                "day": day,
+                "part": part,
+                "answer": answer.text,
+                "parse_time": answer.parse_time.as_secs_f64(),
+                "solve_time": answer.solve_time.as_secs_f64(),
+            });
+            if let Some(memory) = answer.memory {
+                record["peak_heap"] = json!(memory.peak);
+                record["allocations"] = json!(memory.allocations);
+                record["allocated_bytes"] = json!(memory.allocated);
+            }
+            println!("{}", record)
+        }
+        (Format::Json, None) => println!(
+            "{}",
+            json!({
+                "type": "answer",
+                "day": day,
+                "part": part,
+                "answer": null,
+                "parse_time": null,
+                "solve_time": null,
+            })
+        ),
+    }
+}
+
+/// One part of a day to solve on the thread pool
+struct Task<'a> {
+    day: &'static Day,
+    part: u8,
+    input: &'a str,
+}
+
+/// Result of a task, with when it started and finished relative to the
+/// start of the run
+struct Outcome {
+    day: u8,
+    part: u8,
+    result: Result<Answer, AocError>,
+    started: Duration,
+    finished: Duration,
+}
+
+impl Outcome {
+    fn time(&self) -> Duration {
+        self.finished - self.started
+    }
+}
+
+/// Prints how long each day took from its first part starting to its last
+/// finishing, and the longest part, which no number of threads can beat
+fn print_timings(format: Format, outcomes: &[Outcome], total: Duration) {
+    let mut days: Vec<(u8, Duration, Duration)> = Vec::new();
+    for outcome in outcomes {
+        match days.last_mut() {
+            Some((day, started, finished)) if *day == outcome.day => {
+                *started = (*started).min(outcome.started);
+                *finished = (*finished).max(outcome.finished);
+            }
+            _ => days.push((outcome.day, outcome.started, outcome.finished)),
+        }
+    }
+    let critical = outcomes.iter().max_by_key(|o| o.time());
+
+    match format {
+        Format::Human => {
+            for (day, started, finished) in days {
+                println!("Day {} took {:.2?}", day, finished - started);
+            }
+            if let Some(critical) = critical {
+                println!(
+                    "Critical path is day {} part {} at {:.2?}, of {:.2?} in total",
+                    critical.day,
+                    critical.part,
+                    critical.time(),
+                    total
+                );
+            }
+        }
+        Format::Json => {
+            for (day, started, finished) in days {
+                println!(
+                    "{}",
+                    json!({
+                        "type": "day",
+                        "day": day,
+                        "wall_time": (finished - started).as_secs_f64(),
+                    })
+                );
+            }
+            println!(
+                "{}",
+                json!({
+                    "type": "summary",
+                    "wall_time": total.as_secs_f64(),
+                    "critical_path": critical.map(|critical| json!({
+                        "day": critical.day,
+                        "part": critical.part,
+                        "time": critical.time().as_secs_f64(),
+                    })),
+                })
+            );
+        }
+    }
+}
+
+fn run(args: RunArgs) -> bool {
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let days: Vec<(&Day, PathBuf)> = if args.all {
+        days::DAYS
+            .iter()
+            .map(|day| (day, input::default_path(day.number)))
+            .collect()
+    } else {
+        let number = args.day.expect("clap requires a day without --all");
+        let Some(day) = days::get(number) else {
+            eprintln!("Day {} has not been solved", number);
+            return false;
+        };
+        let path = args.input.unwrap_or_else(|| input::default_path(number));
+        vec![(day, path)]
+    };
+
+    let mut ok = true;
+    let mut inputs = Vec::new();
+    for (day, path) in days {
+        match input::read_to_string(path) {
+            Ok(input) => inputs.push((day, input)),
+            Err(e) => {
+                eprintln!("Day {}: {}", day.number, e);
+                ok = false;
+            }
+        }
+    }
+
+    let tasks = inputs
+        .iter()
+        .flat_map(|(day, input)| {
+            parts.iter().map(|&part| Task {
+                day,
+                part,
+                input: input.as_str(),
+            })
+        })
+        .collect();
+    let jobs = args
+        .jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get);
+
+    let start = Instant::now();
+    let mut outcomes = Vec::new();
+    pool::map_ordered(
+        tasks,
+        jobs,
+        |task| {
+            let solve = task
+                .day
+                .part(task.part)
+                .expect("clap only accepts parts 1 and 2");
+            let started = start.elapsed();
+            let result = solve(task.input);
+            Outcome {
+                day: task.day.number,
+                part: task.part,
+                result,
+                started,
+                finished: start.elapsed(),
+            }
+        },
+        |outcome| {
+            match &outcome.result {
+                Ok(answer) => print_answer(args.format, outcome.day, outcome.part, Some(answer)),
+                Err(AocError::Unsolved { .. }) => {
+                    print_answer(args.format, outcome.day, outcome.part, None)
+                }
+                Err(e) => {
+                    eprintln!("{}", e);
+                    ok = false;
+                }
+            }
+            outcomes.push(outcome);
+        },
+    );
+
+    if args.all {
+        print_timings(args.format, &outcomes, start.elapsed());
+    }
+    ok
+}
+
+fn new(args: NewArgs) -> bool {
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), args.day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            true
+        }
+        Err(e) => {
+            eprintln!("Unable to generate day {}: {}", args.day, e);
+            false
+        }
+    }
+}
+
+fn gen(args: GenArgs) -> bool {
+    match generate::input(args.day, args.size, args.seed) {
+        Some(input) => {
+            print!("{}", input);
+            true
+        }
+        None => {
+            eprintln!("Day {} has no input generator", args.day);
+            false
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let ok = match cli.command {
+        Command::Run(args) => run(args),
+        Command::New(args) => new(args),
+        Command::Gen(args) => gen(args),
+    };
+
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -1,37 +1,64 @@
-use std::collections::BinaryHeap;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use advent_22::{
+    days::day01::{self, CalorieParams, Day01},
+    input::InputArgs,
+    AocError, Solution,
+};
+use clap::{Parser, ValueEnum};
 
-fn main() {
-    let mut totals = BinaryHeap::new();
-    let mut current_total = 0;
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Table with a histogram of the totals
+    Human,
+    /// One JSON object
+    Json,
+}
 
-    for line in read_lines("./input/day-1.txt").unwrap().flatten() {
-        if line.is_empty() {
-            totals.push(current_total);
-            current_total = 0;
-            continue;
-        }
+/// Solve day 1 of Advent of Code 2022
+#[derive(Parser)]
+struct Cli {
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Number of elves to add up the calories of in part two
+    #[arg(long, default_value_t = CalorieParams::default().top, conflicts_with = "stats")]
+    top: usize,
 
-        let value: u32 = line.parse().unwrap();
-        current_total += value;
+    /// Only list the top elves, reading the input a line at a time rather
+    /// than all at once
+    #[arg(long, conflicts_with = "stats")]
+    stream: bool,
+
+    /// Summarise the calories carried across the expedition instead
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "human")]
+    stats: Option<Format>,
+}
+
+fn main() -> Result<(), AocError> {
+    let cli = Cli::parse();
+    if let Some(format) = cli.stats {
+        let (totals, items) = day01::tally(&cli.input.read(Day01::DAY)?)?;
+        let stats = day01::stats(&totals, &items)?;
+        match format {
+            Format::Human => print!("{}", stats),
+            Format::Json => println!("{}", stats.to_json()),
+        }
+        return Ok(());
     }
-    let mut sum = 0;
-    for _ in 0..3 {
-        if let Some(total) = totals.pop() {
-            sum += total;
+    if cli.stream {
+        let reader = cli.input.open(Day01::DAY)?;
+        for (index, total) in day01::top_n_streaming(reader, cli.top)? {
+            println!("Elf {} carries {}", index + 1, total);
         }
+        return Ok(());
     }
-    println!("Sum of top 3 seen: {}", sum);
-}
 
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    let input = cli.input.read(Day01::DAY)?;
+    let parsed = Day01::parse(&input)?;
+    let params = CalorieParams { top: cli.top };
+    println!("Part one answer is: {}", Day01::part_one(&parsed, &params)?);
+    println!("Part two answer is: {}", Day01::part_two(&parsed, &params)?);
+    for (index, total) in day01::top_n_calories(&parsed, cli.top) {
+        println!("Elf {} carries {}", index + 1, total);
+    }
+    Ok(())
 }
@@ -0,0 +1,480 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt, io::BufRead};
+
+use crate::{
+    generate::Rng,
+    input::{self, InputError},
+    AocError, Solution,
+};
+
+pub struct CalorieParams {
+    /// Number of elves whose calories part two adds up
+    pub top: usize,
+}
+
+impl Default for CalorieParams {
+    fn default() -> Self {
+        Self { top: 3 }
+    }
+}
+
+/// Index and total of the `n` elves carrying the most calories, most first.
+/// Elves with equal totals are listed in input order.
+pub fn top_n_calories(totals: &[u32], n: usize) -> Vec<(usize, u32)> {
+    let mut heap: BinaryHeap<(u32, Reverse<usize>)> = totals
+        .iter()
+        .enumerate()
+        .map(|(index, &total)| (total, Reverse(index)))
+        .collect();
+    (0..n)
+        .map_while(|_| heap.pop())
+        .map(|(total, Reverse(index))| (index, total))
+        .collect()
+}
+
+/// Same as `top_n_calories`, but reads the inventory a line at a time and
+/// only keeps the `n` largest totals seen so far, so runs in O(n) memory
+/// however many elves there are
+pub fn top_n_streaming(mut reader: impl BufRead, n: usize) -> Result<Vec<(usize, u32)>, AocError> {
+    // Smallest total at the top, with later elves losing ties. The heap
+    // grows as elves are read rather than trusting `n` to be small.
+    let mut heap: BinaryHeap<Reverse<(u32, Reverse<usize>)>> = BinaryHeap::new();
+    let mut keep = |total: u32, index: usize| {
+        heap.push(Reverse((total, Reverse(index))));
+        if heap.len() > n {
+            heap.pop();
+        }
+    };
+
+    let mut line = String::new();
+    let mut line_number = 0;
+    let mut elves = 0;
+    let mut current: Option<u32> = None;
+    loop {
+        line.clear();
+        line_number += 1;
+        let read = reader.read_line(&mut line).map_err(InputError::unnamed)?;
+        let item = line.trim_end_matches(['\r', '\n']);
+
+        if item.is_empty() {
+            if let Some(total) = current.take() {
+                keep(total, elves);
+                elves += 1;
+            }
+            if read == 0 {
+                break;
+            }
+            continue;
+        }
+
+        let value: u32 = item
+            .parse()
+            .map_err(|e| AocError::parse(Day01::DAY, line_number, item, e))?;
+        let total = current.unwrap_or_default().checked_add(value);
+        current = Some(total.ok_or_else(|| overflowed(elves))?);
+    }
+
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| (index, total))
+        .collect())
+}
+
+/// Calories of each item, grouped by the elf carrying them
+fn inventory(input: &str) -> Result<Vec<Vec<u32>>, AocError> {
+    input::parse_groups(Day01::DAY, input, str::parse::<u32>)
+}
+
+/// Total calories and number of items carried by each elf
+pub fn tally(input: &str) -> Result<(Vec<u32>, Vec<usize>), AocError> {
+    let inventory = inventory(input)?;
+    let totals = inventory
+        .iter()
+        .enumerate()
+        .map(|(index, items)| total(index, items))
+        .collect::<Result<_, _>>()?;
+    Ok((totals, inventory.iter().map(Vec::len).collect()))
+}
+
+/// Calories carried by the elf at `index`
+fn total(index: usize, items: &[u32]) -> Result<u32, AocError> {
+    items
+        .iter()
+        .try_fold(0u32, |sum, &item| sum.checked_add(item))
+        .ok_or_else(|| overflowed(index))
+}
+
+fn no_elves() -> AocError {
+    AocError::solve(Day01::DAY, "No elves in the inventory")
+}
+
+fn overflowed(index: usize) -> AocError {
+    let reason = format!("Calories carried by elf {} overflowed", index + 1);
+    AocError::solve(Day01::DAY, reason)
+}
+
+/// Elves whose totals fall in the range `from..=to`
+#[derive(Debug, PartialEq)]
+pub struct Bucket {
+    pub from: u32,
+    pub to: u32,
+    pub elves: usize,
+}
+
+/// Summary of the calories carried across the whole expedition
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub elves: usize,
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation of the totals
+    pub std_dev: f64,
+    pub min: u32,
+    pub max: u32,
+    pub min_items: usize,
+    pub mean_items: f64,
+    pub max_items: usize,
+    /// Up to `HISTOGRAM_BUCKETS` equal width ranges from `min` to `max`
+    pub histogram: Vec<Bucket>,
+}
+
+const HISTOGRAM_BUCKETS: u64 = 10;
+
+/// Longest bar drawn in the text histogram
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Statistics of each elf's total calories and number of items, as returned
+/// by `tally`
+pub fn stats(totals: &[u32], items: &[usize]) -> Result<Stats, AocError> {
+    let mut totals = totals.to_vec();
+    if totals.is_empty() {
+        return Err(no_elves());
+    }
+    totals.sort_unstable();
+
+    let elves = totals.len();
+    let (min, max) = (totals[0], totals[elves - 1]);
+    let mean = totals.iter().map(|&t| t as f64).sum::<f64>() / elves as f64;
+    let median = if elves % 2 == 1 {
+        totals[elves / 2] as f64
+    } else {
+        (totals[elves / 2 - 1] as f64 + totals[elves / 2] as f64) / 2.0
+    };
+    let variance = totals
+        .iter()
+        .map(|&t| (t as f64 - mean).powi(2))
+        .sum::<f64>()
+        / elves as f64;
+
+    let item_count: usize = items.iter().sum();
+
+    // Work in u64 so the width of a bucket reaching u32::MAX can't overflow
+    let span = (max - min) as u64 + 1;
+    let buckets = HISTOGRAM_BUCKETS.min(elves as u64).min(span);
+    let width = span.div_ceil(buckets);
+    let mut histogram: Vec<Bucket> = (0..buckets)
+        .map(|i| min as u64 + i * width)
+        // Rounding the width up can leave nothing for the last buckets
+        .take_while(|&from| from <= max as u64)
+        .map(|from| Bucket {
+            from: from as u32,
+            to: (from + width - 1).min(max as u64) as u32,
+            elves: 0,
+        })
+        .collect();
+    for &t in &totals {
+        histogram[((t - min) as u64 / width) as usize].elves += 1;
+    }
+
+    Ok(Stats {
+        elves,
+        mean,
+        median,
+        std_dev: variance.sqrt(),
+        min,
+        max,
+        min_items: items.iter().copied().min().unwrap_or_default(),
+        mean_items: item_count as f64 / elves as f64,
+        max_items: items.iter().copied().max().unwrap_or_default(),
+        histogram,
+    })
+}
+
+impl Stats {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "elves": self.elves,
+            "mean": self.mean,
+            "median": self.median,
+            "std_dev": self.std_dev,
+            "min": self.min,
+            "max": self.max,
+            "items_per_elf": {
+                "min": self.min_items,
+                "mean": self.mean_items,
+                "max": self.max_items,
+            },
+            "histogram": self.histogram.iter().map(|bucket| serde_json::json!({
+                "from": bucket.from,
+                "to": bucket.to,
+                "elves": bucket.elves,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Elves: {}", self.elves)?;
+        writeln!(f, "Calories: {} to {}", self.min, self.max)?;
+        writeln!(f, "Mean: {:.1}", self.mean)?;
+        writeln!(f, "Median: {:.1}", self.median)?;
+        writeln!(f, "Standard deviation: {:.1}", self.std_dev)?;
+        writeln!(
+            f,
+            "Items per elf: {} to {}, {:.1} on average",
+            self.min_items, self.max_items, self.mean_items
+        )?;
+
+        let most = self.histogram.iter().map(|b| b.elves).max().unwrap_or(1);
+        let label_width = self.max.to_string().len();
+        for bucket in &self.histogram {
+            // Round up so every non-empty bucket gets at least some bar
+            let bar = (bucket.elves * HISTOGRAM_WIDTH).div_ceil(most);
+            writeln!(
+                f,
+                "{:>w$} - {:>w$} | {:<bar_width$} {}",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.elves,
+                w = label_width,
+                bar_width = HISTOGRAM_WIDTH
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<u32>;
+    type Params = CalorieParams;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    /// Total calories carried by each elf
+    fn parse(input: &str) -> Result<Vec<u32>, AocError> {
+        Ok(tally(input)?.0)
+    }
+
+    fn part_one(totals: &Vec<u32>, _: &CalorieParams) -> Result<u32, AocError> {
+        totals.iter().copied().max().ok_or_else(no_elves)
+    }
+
+    fn part_two(totals: &Vec<u32>, params: &CalorieParams) -> Result<u32, AocError> {
+        if totals.is_empty() {
+            return Err(no_elves());
+        }
+        top_n_calories(totals, params.top)
+            .into_iter()
+            .try_fold(0u32, |sum, (_, total)| sum.checked_add(total))
+            .ok_or_else(|| {
+                let reason = format!("Sum of the top {} overflowed", params.top);
+                AocError::solve(Self::DAY, reason)
+            })
+    }
+}
+
+/// Input listing each total as a single item, which parses back to the same
+/// totals
+pub fn serialize(totals: &[u32]) -> String {
+    totals
+        .iter()
+        .map(|total| format!("{}\n\n", total))
+        .collect()
+}
+
+/// Random list of elves and the calories of the food they carry
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        for _ in 0..rng.between(1, 15) {
+            input += &format!("{}\n", rng.between(1000, 60000));
+        }
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let totals = Day01::parse(example!(1)).unwrap();
+        assert_eq!(
+            Day01::part_one(&totals, &Default::default()).unwrap(),
+            24000
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let totals = Day01::parse(example!(1)).unwrap();
+        assert_eq!(
+            Day01::part_two(&totals, &Default::default()).unwrap(),
+            45000
+        );
+    }
+
+    #[test]
+    fn no_elves_is_an_error() {
+        let totals = Day01::parse("\n\n").unwrap();
+        let params = CalorieParams::default();
+        assert!(matches!(
+            Day01::part_one(&totals, &params),
+            Err(AocError::Solve { .. })
+        ));
+        assert!(matches!(
+            Day01::part_two(&totals, &params),
+            Err(AocError::Solve { .. })
+        ));
+    }
+
+    #[test]
+    fn last_elf_counts_without_trailing_blank_line() {
+        let with_separator = "1000\n2000\n\n3000\n\n";
+        let expected = vec![3000, 3000];
+        assert_eq!(Day01::parse(with_separator).unwrap(), expected);
+        assert_eq!(Day01::parse("1000\n2000\n\n3000\n").unwrap(), expected);
+        assert_eq!(Day01::parse("1000\n2000\n\n3000").unwrap(), expected);
+        assert_eq!(
+            Day01::parse("1000\r\n2000\r\n\r\n3000\r\n").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn streaming_matches_buffered() {
+        for input in [example!(1), "5\n\n7\n\n5\n", "5\r\n\r\n7\r\n\r\n5", ""] {
+            let totals = Day01::parse(input).unwrap();
+            for n in 0..5 {
+                assert_eq!(
+                    top_n_streaming(input.as_bytes(), n).unwrap(),
+                    top_n_calories(&totals, n)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn streaming_keeps_memory_bounded_by_elves() {
+        let top = top_n_streaming("1\n\n2\n".as_bytes(), usize::MAX).unwrap();
+        assert_eq!(top, vec![(1, 2), (0, 1)]);
+    }
+
+    #[test]
+    fn streaming_overflow_matches_buffered() {
+        let input = "1\n\n4294967295\n1\n";
+        assert_eq!(
+            top_n_streaming(input.as_bytes(), 3)
+                .unwrap_err()
+                .to_string(),
+            Day01::parse(input).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn streaming_read_failure_is_an_input_error() {
+        let err = top_n_streaming(&[0xff, b'\n'][..], 3).unwrap_err();
+        assert!(matches!(err, AocError::Input(_)));
+    }
+
+    #[test]
+    fn streaming_reports_line() {
+        let err = top_n_streaming("1\n\n2\nx\n".as_bytes(), 3).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1, line 4: invalid digit found in string in \"x\""
+        );
+    }
+
+    #[test]
+    fn stats_example() {
+        let (totals, items) = tally(example!(1)).unwrap();
+        let stats = stats(&totals, &items).unwrap();
+        assert_eq!(stats.elves, 5);
+        assert_eq!((stats.min, stats.max), (4000, 24000));
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert!((stats.std_dev - 6985.7).abs() < 0.1);
+        assert_eq!((stats.min_items, stats.max_items), (1, 3));
+        assert_eq!(stats.mean_items, 2.0);
+        let counts: Vec<usize> = stats.histogram.iter().map(|b| b.elves).collect();
+        assert_eq!(counts, vec![2, 2, 0, 0, 1]);
+        assert_eq!(stats.histogram[0].from, 4000);
+        assert_eq!(stats.histogram.last().unwrap().to, 24000);
+    }
+
+    #[test]
+    fn stats_of_nothing_is_an_error() {
+        assert!(stats(&[], &[]).is_err());
+    }
+
+    #[test]
+    fn histogram_stops_at_max_near_u32_limit() {
+        // 11 possible totals in 10 buckets rounds the width up to 2, leaving
+        // the last buckets starting past u32::MAX
+        let mut totals = vec![u32::MAX - 10; 9];
+        totals.push(u32::MAX);
+        let stats = stats(&totals, &[1; 10]).unwrap();
+        assert_eq!(stats.histogram.len(), 6);
+        assert!(stats.histogram.iter().all(|b| b.from >= stats.min));
+        assert_eq!(stats.histogram.last().unwrap().to, u32::MAX);
+        let counted: usize = stats.histogram.iter().map(|b| b.elves).sum();
+        assert_eq!(counted, 10);
+    }
+
+    #[test]
+    fn top_elves_are_listed_with_indices() {
+        assert_eq!(
+            top_n_calories(&[6000, 4000, 11000, 24000, 10000], 3),
+            vec![(3, 24000), (2, 11000), (4, 10000)]
+        );
+        assert_eq!(top_n_calories(&[5, 7, 5], 5), vec![(1, 7), (0, 5), (2, 5)]);
+        assert!(top_n_calories(&[5, 7], 0).is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 1..50usize) {
+            let totals = Day01::parse(&generate(&mut Rng::new(seed), size)).unwrap();
+            prop_assert_eq!(Day01::parse(&serialize(&totals)).unwrap(), totals);
+        }
+
+        #[test]
+        fn histogram_counts_every_elf(seed: u64, size in 1..50usize) {
+            let (totals, items) = tally(&generate(&mut Rng::new(seed), size)).unwrap();
+            let stats = stats(&totals, &items).unwrap();
+            let counted: usize = stats.histogram.iter().map(|b| b.elves).sum();
+            prop_assert_eq!(counted, stats.elves);
+            prop_assert!(stats.min as f64 <= stats.median && stats.median <= stats.max as f64);
+        }
+
+        #[test]
+        fn streaming_matches_buffered_on_generated(seed: u64, size in 1..50usize, n in 0..6usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            let totals = Day01::parse(&input).unwrap();
+            prop_assert_eq!(
+                top_n_streaming(input.as_bytes(), n).unwrap(),
+                top_n_calories(&totals, n)
+            );
+        }
+    }
+}
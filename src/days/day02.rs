@@ -0,0 +1,234 @@
+use std::{fmt, str::FromStr};
+
+use crate::{generate::Rng, input, AocError, Solution};
+
+#[derive(Debug, PartialEq)]
+pub enum Play {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+pub enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+/// Second column of the strategy guide, which means a play or an outcome
+/// depending on how the guide is read
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+/// How to read the second column of the strategy guide
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StrategyInterpretation {
+    /// X, Y and Z are rock, paper and scissors, as first guessed in part one
+    Plays,
+    /// X, Y and Z are the round needing to be lost, drawn or won, as the elf
+    /// explains in part two
+    Outcomes,
+}
+
+impl Response {
+    fn play(self) -> Play {
+        match self {
+            Response::X => Play::Rock,
+            Response::Y => Play::Paper,
+            Response::Z => Play::Scissors,
+        }
+    }
+
+    fn outcome(self) -> Outcome {
+        match self {
+            Response::X => Outcome::Loss,
+            Response::Y => Outcome::Draw,
+            Response::Z => Outcome::Win,
+        }
+    }
+}
+
+impl FromStr for Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "X" => Ok(Self::X),
+            "Y" => Ok(Self::Y),
+            "Z" => Ok(Self::Z),
+            _ => Err(format!("Invalid response {:?}", s)),
+        }
+    }
+}
+
+impl FromStr for Play {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "A" => Ok(Self::Rock),
+            "B" => Ok(Self::Paper),
+            "C" => Ok(Self::Scissors),
+            _ => Err(format!(
+                "Cannot convert {:?} to rock, paper, or scissors",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::X => write!(f, "X"),
+            Self::Y => write!(f, "Y"),
+            Self::Z => write!(f, "Z"),
+        }
+    }
+}
+
+impl fmt::Display for Play {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rock => write!(f, "A"),
+            Self::Paper => write!(f, "B"),
+            Self::Scissors => write!(f, "C"),
+        }
+    }
+}
+
+impl Play {
+    fn value(&self) -> u32 {
+        match self {
+            Play::Rock => 1,
+            Play::Paper => 2,
+            Play::Scissors => 3,
+        }
+    }
+
+    /// 0 for loss, 3 for draw, 6 for win + value of play
+    fn score(player: &Play, opponent: &Play) -> u32 {
+        let score_from_game = match (player, opponent) {
+            (Play::Rock, Play::Rock) => 3,
+            (Play::Rock, Play::Paper) => 0,
+            (Play::Rock, Play::Scissors) => 6,
+            (Play::Paper, Play::Rock) => 6,
+            (Play::Paper, Play::Paper) => 3,
+            (Play::Paper, Play::Scissors) => 0,
+            (Play::Scissors, Play::Rock) => 0,
+            (Play::Scissors, Play::Paper) => 6,
+            (Play::Scissors, Play::Scissors) => 3,
+        };
+        score_from_game + player.value()
+    }
+
+    /// Determines the play required for the desired outcome
+    fn play_needed(opponent: &Play, outcome: &Outcome) -> Play {
+        match (opponent, outcome) {
+            (Play::Rock, Outcome::Win) => Play::Paper,
+            (Play::Rock, Outcome::Loss) => Play::Scissors,
+            (Play::Rock, Outcome::Draw) => Play::Rock,
+            (Play::Paper, Outcome::Win) => Play::Scissors,
+            (Play::Paper, Outcome::Loss) => Play::Rock,
+            (Play::Paper, Outcome::Draw) => Play::Paper,
+            (Play::Scissors, Outcome::Win) => Play::Rock,
+            (Play::Scissors, Outcome::Loss) => Play::Paper,
+            (Play::Scissors, Outcome::Draw) => Play::Scissors,
+        }
+    }
+}
+
+/// Score from following the strategy guide, reading it as `interpretation`
+pub fn total_score(rounds: &[(Play, Response)], interpretation: StrategyInterpretation) -> u32 {
+    rounds
+        .iter()
+        .map(|(opponent, response)| {
+            let you = match interpretation {
+                StrategyInterpretation::Plays => response.play(),
+                StrategyInterpretation::Outcomes => {
+                    Play::play_needed(opponent, &response.outcome())
+                }
+            };
+            Play::score(&you, opponent)
+        })
+        .sum()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<(Play, Response)>;
+    type Params = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input::parse_lines(Self::DAY, input, |line| {
+            let (opponent, response) = line.split_once(' ').ok_or("Expected two columns")?;
+            let opponent: Play = opponent.parse()?;
+            let response: Response = response.parse()?;
+            Ok::<_, String>((opponent, response))
+        })
+    }
+
+    fn part_one(rounds: &Self::Input, _: &()) -> Result<u32, AocError> {
+        Ok(total_score(rounds, StrategyInterpretation::Plays))
+    }
+
+    fn part_two(rounds: &Self::Input, _: &()) -> Result<u32, AocError> {
+        Ok(total_score(rounds, StrategyInterpretation::Outcomes))
+    }
+}
+
+/// Strategy guide with one line per round
+pub fn serialize(rounds: &[(Play, Response)]) -> String {
+    rounds
+        .iter()
+        .map(|(opponent, response)| format!("{} {}\n", opponent, response))
+        .collect()
+}
+
+/// Random strategy guide with `size` rounds
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let rounds = Day02::parse(example!(2)).unwrap();
+        assert_eq!(Day02::part_one(&rounds, &()).unwrap(), 15);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let rounds = Day02::parse(example!(2)).unwrap();
+        assert_eq!(Day02::part_two(&rounds, &()).unwrap(), 12);
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 0..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day02::parse(&input).unwrap()), input);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::{generate::Rng, input, AocError, Solution};
+
+trait Priority {
+    /// If value has priority returns Some(priority), else None
+    fn priority(self) -> Option<u32>;
+}
+
+impl Priority for char {
+    fn priority(self) -> Option<u32> {
+        match self {
+            x if x.is_ascii_lowercase() => Some(x as u32 - 'a' as u32 + 1),
+            x if x.is_ascii_uppercase() => Some(x as u32 - 'A' as u32 + 27),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn expected_priority() {
+    // a = 1, z = 26, A = 27, Z = 52
+    assert_eq!('a'.priority(), Some(1));
+    assert_eq!('z'.priority(), Some(26));
+    assert_eq!('A'.priority(), Some(27));
+    assert_eq!('Z'.priority(), Some(52));
+    assert_eq!('*'.priority(), None);
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<String>;
+    type Params = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, AocError> {
+        input::parse_lines(Self::DAY, input, |line| {
+            match line.chars().find(|c| c.priority().is_none()) {
+                Some(c) => Err(format!("Unexpected item {:?}", c)),
+                None => Ok(line.to_string()),
+            }
+        })
+    }
+
+    fn part_one(_: &Vec<String>, _: &()) -> Result<u32, AocError> {
+        Err(AocError::Unsolved {
+            day: Self::DAY,
+            part: 1,
+        })
+    }
+
+    fn part_two(rucksacks: &Vec<String>, _: &()) -> Result<u32, AocError> {
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(AocError::solve(
+                Self::DAY,
+                "Rucksacks can't be split into groups of three",
+            ));
+        }
+
+        let mut sum = 0;
+        for (first, second, third) in rucksacks.iter().tuples() {
+            // Collect into set to deduplicate
+            let first: HashSet<char> = first.chars().collect();
+
+            for item in first {
+                if second.contains(item) && third.contains(item) {
+                    sum += item.priority().unwrap_or_default();
+                }
+            }
+        }
+        Ok(sum)
+    }
+}
+
+/// List of rucksacks, one per line
+pub fn serialize(rucksacks: &[String]) -> String {
+    rucksacks.iter().map(|r| format!("{}\n", r)).collect()
+}
+
+/// Random rucksacks in `size` groups of three, each rucksack having exactly
+/// one item in both compartments and each group exactly one shared badge
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        rng.shuffle(&mut items);
+        let (badge, others) = items.split_first().unwrap();
+        for pool in others.chunks(17) {
+            // Each rucksack uses its own items, so only the badge is shared
+            let (shared, rest) = pool.split_first().unwrap();
+            let (left_pool, right_pool) = rest.split_at(8);
+            let extra = rng.index(8);
+            let mut left = vec![*shared, *badge];
+            left.extend((0..extra).map(|_| *rng.choose(left_pool)));
+            let mut right = vec![*shared];
+            right.extend((0..=extra).map(|_| *rng.choose(right_pool)));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            input.extend(left.into_iter().chain(right));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 1..20usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day03::parse(&input).unwrap()), input);
+        }
+    }
+}
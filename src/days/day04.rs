@@ -0,0 +1,129 @@
+use itertools::Itertools;
+
+use crate::{generate::Rng, input, AocError, Solution};
+
+type Assignment = (u32, u32);
+
+/// Whether either assignment covers every section of the other
+fn fully_contains(
+    (first_start, first_stop): Assignment,
+    (second_start, second_stop): Assignment,
+) -> bool {
+    let first_within_second = first_start >= second_start && first_stop <= second_stop;
+    let second_within_first = second_start >= first_start && second_stop <= first_stop;
+    first_within_second || second_within_first
+}
+
+/// Whether the assignments share any sections
+fn overlaps((a_start, a_stop): Assignment, (b_start, b_stop): Assignment) -> bool {
+    a_start <= b_stop && b_start <= a_stop
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<(Assignment, Assignment)>;
+    type Params = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input::parse_lines(Self::DAY, input, |line| {
+            let sections: Vec<u32> = line
+                .split(&['-', ','])
+                .map(|s| s.parse::<u32>())
+                .try_collect()
+                .map_err(|e| e.to_string())?;
+            let (a_start, a_stop, b_start, b_stop) = sections
+                .into_iter()
+                .collect_tuple()
+                .ok_or("Expected two ranges")?;
+            if a_start > a_stop || b_start > b_stop {
+                return Err("Range ends before it starts".to_string());
+            }
+            Ok::<_, String>(((a_start, a_stop), (b_start, b_stop)))
+        })
+    }
+
+    fn part_one(pairs: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let count = pairs.iter().filter(|(a, b)| fully_contains(*a, *b)).count();
+        Ok(count)
+    }
+
+    fn part_two(pairs: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let count = pairs.iter().filter(|(a, b)| overlaps(*a, *b)).count();
+        Ok(count)
+    }
+}
+
+/// List of pairs of assignments, one pair per line
+pub fn serialize(pairs: &[(Assignment, Assignment)]) -> String {
+    pairs
+        .iter()
+        .map(|((a, b), (c, d))| format!("{}-{},{}-{}\n", a, b, c, d))
+        .collect()
+}
+
+/// Random list of `size` pairs of section assignments
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.between(1, 99);
+        (start, rng.between(start, 99))
+    };
+    (0..size)
+        .map(|_| {
+            let ((a, b), (c, d)) = (range(), range());
+            format!("{}-{},{}-{}\n", a, b, c, d)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert, prop_assert_eq, proptest, strategy::Strategy};
+
+    #[test]
+    fn first_example() {
+        let input = example!(4);
+        let result = Day04::part_one(&Day04::parse(input).unwrap(), &()).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn second_example() {
+        let input = example!(4);
+        let result = Day04::part_two(&Day04::parse(input).unwrap(), &()).unwrap();
+        assert_eq!(result, 4);
+    }
+
+    fn assignment() -> impl Strategy<Value = Assignment> {
+        (0..100u32, 0..100u32).prop_map(|(a, b)| (a.min(b), a.max(b)))
+    }
+
+    proptest! {
+        #[test]
+        fn containment_implies_overlap(a in assignment(), b in assignment()) {
+            prop_assert!(!fully_contains(a, b) || overlaps(a, b));
+        }
+
+        #[test]
+        fn overlap_is_symmetric(a in assignment(), b in assignment()) {
+            prop_assert_eq!(overlaps(a, b), overlaps(b, a));
+            prop_assert_eq!(fully_contains(a, b), fully_contains(b, a));
+        }
+
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 0..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day04::parse(&input).unwrap()), input);
+        }
+    }
+
+    #[test]
+    fn reversed_ranges_are_an_error() {
+        assert!(Day04::parse("5-3,1-9\n").is_err());
+    }
+}
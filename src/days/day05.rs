@@ -0,0 +1,170 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::{generate::Rng, AocError, Solution};
+
+pub struct Cargo {
+    crates: HashMap<usize, Vec<char>>,
+    moves: Vec<(usize, usize, usize)>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Cargo;
+    type Params = ();
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Cargo, AocError> {
+        let mut crates: HashMap<usize, Vec<char>> = HashMap::new();
+
+        // Populate crate information
+        for line in input.lines().filter(|l| l.contains('[')) {
+            for (index, item) in line.char_indices().filter(|(_, c)| c.is_ascii_uppercase()) {
+                let stack_index = (index / 4) + 1;
+                let stack = crates.entry(stack_index).or_default();
+                stack.insert(0, item);
+            }
+        }
+
+        let moves = input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| l.contains("move"))
+            .map(|(index, instruction)| {
+                instruction
+                    .split(' ')
+                    .filter_map(|word| word.parse().ok())
+                    .collect_tuple()
+                    .ok_or_else(|| {
+                        AocError::parse(Self::DAY, index + 1, instruction, "Expected three numbers")
+                    })
+            })
+            .try_collect()?;
+
+        Ok(Cargo { crates, moves })
+    }
+
+    fn part_one(_: &Cargo, _: &()) -> Result<String, AocError> {
+        Err(AocError::Unsolved {
+            day: Self::DAY,
+            part: 1,
+        })
+    }
+
+    fn part_two(cargo: &Cargo, _: &()) -> Result<String, AocError> {
+        let mut crates = cargo.crates.clone();
+
+        // Move boxes
+        for &(quantity, from, to) in cargo.moves.iter() {
+            let stack = crates
+                .get_mut(&from)
+                .ok_or_else(|| AocError::solve(Self::DAY, format!("No stack {}", from)))?;
+            let remaining = stack.len().checked_sub(quantity).ok_or_else(|| {
+                let reason = format!("Can't move {} boxes from stack {}", quantity, from);
+                AocError::solve(Self::DAY, reason)
+            })?;
+            let mut items = stack.split_off(remaining);
+            crates.entry(to).or_default().append(&mut items);
+        }
+
+        // Return top box from each stack as string
+        crates
+            .keys()
+            .sorted()
+            .map(|k| {
+                crates[k]
+                    .last()
+                    .copied()
+                    .ok_or_else(|| AocError::solve(Self::DAY, format!("Stack {} has no boxes", k)))
+            })
+            .collect()
+    }
+}
+
+/// Drawing of the stacks, numbered from 1, followed by a blank line
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let mut drawing = String::new();
+    for row in (0..height).rev() {
+        let line = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .join(" ");
+        drawing += line.trim_end();
+        drawing.push('\n');
+    }
+    drawing += &(1..=stacks.len()).map(|i| format!(" {} ", i)).join(" ");
+    drawing + "\n\n"
+}
+
+/// Drawing of the starting stacks followed by the moves
+pub fn serialize(cargo: &Cargo) -> String {
+    let count = cargo.crates.keys().max().copied().unwrap_or_default();
+    let stacks: Vec<Vec<char>> = (1..=count)
+        .map(|k| cargo.crates.get(&k).cloned().unwrap_or_default())
+        .collect();
+    let moves = cargo
+        .moves
+        .iter()
+        .map(|(quantity, from, to)| format!("move {} from {} to {}\n", quantity, from, to));
+    drawing(&stacks) + &moves.collect::<String>()
+}
+
+/// Random drawing of nine stacks followed by `size` moves, none of which
+/// empty a stack
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut stacks: Vec<Vec<char>> = (0..9)
+        .map(|_| {
+            (0..rng.between(2, 8))
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let mut input = drawing(&stacks);
+    for _ in 0..size {
+        let from = loop {
+            let from = rng.index(stacks.len());
+            if stacks[from].len() >= 2 {
+                break from;
+            }
+        };
+        let to = (from + 1 + rng.index(stacks.len() - 1)) % stacks.len();
+        let quantity = rng.between(1, stacks[from].len() as i64 - 1) as usize;
+        let remaining = stacks[from].len() - quantity;
+        let mut moved = stacks[from].split_off(remaining);
+        stacks[to].append(&mut moved);
+        input += &format!("move {} from {} to {}\n", quantity, from + 1, to + 1);
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn example() {
+        let input = example!(5);
+        assert_eq!(
+            Day05::part_two(&Day05::parse(input).unwrap(), &()).unwrap(),
+            "MCD"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 0..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day05::parse(&input).unwrap()), input);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::{generate::Rng, AocError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = String;
+    type Params = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<String, AocError> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part_one(input: &String, _: &()) -> Result<usize, AocError> {
+        part_one(input).map_err(|e| AocError::solve(Self::DAY, e))
+    }
+
+    fn part_two(input: &String, _: &()) -> Result<usize, AocError> {
+        part_two(input).map_err(|e| AocError::solve(Self::DAY, e))
+    }
+}
+
+pub fn part_one(input: &str) -> Result<usize, &str> {
+    for (index, (a, b, c, d)) in input.chars().tuple_windows().enumerate() {
+        if a != b && a != c && a != d && b != c && b != d && c != d {
+            return Ok(index + 4);
+        }
+    }
+    Err("Unable to find start signal")
+}
+
+pub fn part_two(input: &str) -> Result<usize, &str> {
+    let chars: Vec<char> = input.chars().collect();
+    for (index, window) in chars.windows(14).enumerate() {
+        if window.iter().all_unique() {
+            return Ok(index + 14);
+        }
+    }
+    Err("Unable to find start signal")
+}
+
+pub fn part_two_hashmap(input: &str) -> Result<usize, &str> {
+    let chars: Vec<char> = input.chars().collect();
+    for (index, window) in chars.windows(14).enumerate() {
+        let mut counts = HashMap::<char, u8>::new();
+        for c in window {
+            let count = counts.entry(*c).or_default();
+            *count += 1;
+        }
+
+        if counts.values().all(|v| *v == 1) {
+            return Ok(index + 14);
+        }
+    }
+    Err("Unable to find start signal")
+}
+
+pub fn part_two_hashset(input: &str) -> Result<usize, &str> {
+    let chars: Vec<char> = input.chars().collect();
+    for (index, window) in chars.windows(14).enumerate() {
+        let mut set = HashSet::new();
+        if window.iter().all(|c| set.insert(c)) {
+            return Ok(index + 14);
+        }
+    }
+    Err("Unable to find start signal")
+}
+
+/// Datastream on a single line
+pub fn serialize(stream: &str) -> String {
+    format!("{}\n", stream)
+}
+
+/// Random datastream of at least `size` characters ending in a run of 14
+/// different characters
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // Only 13 letters are used before the marker, so it can't appear early
+    let mut input: String = (0..size.saturating_sub(14))
+        .map(|_| (b'a' + rng.below(13) as u8) as char)
+        .collect();
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut marker);
+    input.extend(&marker[..14]);
+    input.push('\n');
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn it_works() {
+        assert_eq!(part_one("bvwbjplbgvbhsrlpgdmjqwftvncz"), Ok(5));
+        assert_eq!(part_one("nppdvjthqldpwncqszvftbrmjlhg"), Ok(6));
+        assert_eq!(part_one("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Ok(10));
+        assert_eq!(part_one("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Ok(11));
+
+        assert_eq!(part_two("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), Ok(19));
+        assert_eq!(part_two("bvwbjplbgvbhsrlpgdmjqwftvncz"), Ok(23));
+        assert_eq!(part_two("nppdvjthqldpwncqszvftbrmjlhg"), Ok(23));
+        assert_eq!(part_two("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"), Ok(29));
+        assert_eq!(part_two("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"), Ok(26));
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 0..200usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day06::parse(&input).unwrap()), input);
+        }
+
+        #[test]
+        fn part_two_variants_agree(stream in "[a-p]{0,100}") {
+            let expected = part_two(&stream);
+            prop_assert_eq!(part_two_hashmap(&stream), expected);
+            prop_assert_eq!(part_two_hashset(&stream), expected);
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use itertools::Itertools;
+
+use crate::{generate::Rng, AocError, Solution};
+
+type DirRef = Rc<RefCell<Dir>>;
+
+#[derive(Default, Debug)]
+struct Dir {
+    parent: Option<DirRef>,
+    size_of_files: usize,
+    sub_directories: HashMap<String, DirRef>,
+}
+
+fn parse_input(input: &str) -> Result<DirRef, AocError> {
+    let root = Rc::new(RefCell::new(Dir::default()));
+    let mut cursor = Rc::clone(&root);
+    let error = |index: usize, line: &str, reason: &str| {
+        AocError::parse(Day07::DAY, index + 1, line, reason)
+    };
+
+    let mut iter = input.lines().enumerate().peekable();
+    while let Some((index, line)) = iter.next() {
+        if let Some(dest) = line.strip_prefix("$ cd ") {
+            if dest == "/" {
+                cursor = Rc::clone(&root);
+            } else if dest == ".." {
+                let dir = cursor.borrow();
+                let parent = dir
+                    .parent
+                    .as_ref()
+                    .ok_or_else(|| error(index, line, "Already at root"))?;
+                let new_cursor = Rc::clone(parent);
+                drop(dir);
+                cursor = new_cursor;
+            } else {
+                let dir = cursor.borrow();
+                let sub_directory = dir
+                    .sub_directories
+                    .get(dest)
+                    .ok_or_else(|| error(index, line, "Unknown directory"))?;
+                let new_cursor = Rc::clone(sub_directory);
+                drop(dir);
+                cursor = new_cursor;
+            }
+            continue;
+        }
+
+        if line != "$ ls" {
+            return Err(error(index, line, "Expected a command"));
+        }
+
+        while let Some((index, line)) = iter.next_if(|(_, l)| !l.starts_with('$')) {
+            let (dir_or_size, name) = line
+                .split_once(' ')
+                .ok_or_else(|| error(index, line, "Expected size or dir and a name"))?;
+
+            if dir_or_size == "dir" {
+                let new_dir = Rc::new(RefCell::new(Dir {
+                    parent: Some(Rc::clone(&cursor)),
+                    size_of_files: 0,
+                    sub_directories: HashMap::new(),
+                }));
+                (*cursor)
+                    .borrow_mut()
+                    .sub_directories
+                    .insert(name.into(), new_dir);
+            } else {
+                let size = dir_or_size
+                    .parse::<usize>()
+                    .map_err(|_| error(index, line, "Invalid file size"))?;
+                let mut dir = (*cursor).borrow_mut();
+                dir.size_of_files = dir
+                    .size_of_files
+                    .checked_add(size)
+                    .ok_or_else(|| error(index, line, "Directory size overflowed"))?;
+            }
+        }
+    }
+    Ok(root)
+}
+
+/// Total size of `dir`, or None if it overflows
+fn get_sizes_recursive(
+    dir: &DirRef,
+    path: &str,
+    sizes: &mut HashMap<String, usize>,
+) -> Option<usize> {
+    let current_dir = dir.borrow();
+
+    if current_dir.sub_directories.is_empty() {
+        return Some(current_dir.size_of_files);
+    }
+
+    let mut total_size = current_dir.size_of_files;
+    for (name, subdir) in current_dir.sub_directories.iter() {
+        let sub_path = format!("{}/{}", path, name);
+        let size = get_sizes_recursive(subdir, &sub_path, sizes)?;
+        sizes.insert(sub_path, size);
+        total_size = total_size.checked_add(size)?;
+    }
+    Some(total_size)
+}
+
+fn get_sizes(root_dir: &DirRef) -> Result<HashMap<String, usize>, AocError> {
+    let mut sizes = HashMap::new();
+    let root_path = String::from("");
+    let root_size = get_sizes_recursive(root_dir, &root_path, &mut sizes)
+        .ok_or_else(|| AocError::solve(Day07::DAY, "Directory size overflowed"))?;
+    sizes.insert(root_path, root_size);
+    Ok(sizes)
+}
+
+pub struct DiskParams {
+    pub max_dir_size: usize,
+    pub disk_size: usize,
+    pub space_needed: usize,
+}
+
+impl Default for DiskParams {
+    fn default() -> Self {
+        Self {
+            max_dir_size: 100000,
+            disk_size: 70000000,
+            space_needed: 30000000,
+        }
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = HashMap<String, usize>;
+    type Params = DiskParams;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    /// Total size of every directory, keyed by path
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let root = parse_input(input)?;
+        get_sizes(&root)
+    }
+
+    fn part_one(sizes: &Self::Input, params: &DiskParams) -> Result<usize, AocError> {
+        // find sum of directories with size of at most max_dir_size
+        Ok(sizes.values().filter(|v| **v <= params.max_dir_size).sum())
+    }
+
+    fn part_two(sizes: &Self::Input, params: &DiskParams) -> Result<usize, AocError> {
+        // find smallest directory that frees up enough space
+        let total_used = sizes.get("").copied().unwrap_or_default();
+        let remaining = params
+            .disk_size
+            .checked_sub(total_used)
+            .ok_or_else(|| AocError::solve(Self::DAY, "Files don't fit on the disk"))?;
+        let target_to_delete = params
+            .space_needed
+            .checked_sub(remaining)
+            .filter(|target| *target > 0)
+            .ok_or_else(|| AocError::solve(Self::DAY, "Already enough space"))?;
+
+        sizes
+            .values()
+            .copied()
+            .filter(|v| *v > target_to_delete)
+            .min()
+            .ok_or_else(|| AocError::solve(Self::DAY, "No directory is large enough to delete"))
+    }
+}
+
+/// Terminal session exploring directories with the given total sizes, with
+/// the files directly inside each directory merged into one
+pub fn serialize(sizes: &HashMap<String, usize>) -> String {
+    fn write_dir(sizes: &HashMap<String, usize>, path: &str, input: &mut String) {
+        let children: Vec<(&str, &String)> = sizes
+            .keys()
+            .filter_map(|key| {
+                let name = key.strip_prefix(path)?.strip_prefix('/')?;
+                (!name.contains('/')).then_some((name, key))
+            })
+            .sorted()
+            .collect();
+        let in_children: usize = children.iter().map(|(_, key)| sizes[*key]).sum();
+
+        *input += "$ ls\n";
+        for (name, _) in &children {
+            *input += &format!("dir {}\n", name);
+        }
+        if sizes[path] > in_children {
+            *input += &format!("{} files\n", sizes[path] - in_children);
+        }
+        for (name, key) in children {
+            *input += &format!("$ cd {}\n", name);
+            write_dir(sizes, key, input);
+            *input += "$ cd ..\n";
+        }
+    }
+
+    let mut input = String::from("$ cd /\n");
+    if sizes.contains_key("") {
+        write_dir(sizes, "", &mut input);
+    }
+    input
+}
+
+/// Random terminal session exploring a tree of `size` directories, with a
+/// total size that leaves the disk between 45 and 65 million full
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    struct Listing {
+        names: Vec<String>,
+        children: Vec<Vec<usize>>,
+        files: Vec<Vec<(String, u64)>>,
+    }
+
+    fn write_dir(listing: &Listing, dir: usize, input: &mut String) {
+        *input += "$ ls\n";
+        for &child in &listing.children[dir] {
+            *input += &format!("dir {}\n", listing.names[child]);
+        }
+        for (name, size) in &listing.files[dir] {
+            *input += &format!("{} {}\n", size, name);
+        }
+        for &child in &listing.children[dir] {
+            *input += &format!("$ cd {}\n", listing.names[child]);
+            write_dir(listing, child, input);
+            *input += "$ cd ..\n";
+        }
+    }
+
+    let count = size.max(1);
+    let mut listing = Listing {
+        names: (0..count)
+            .map(|i| format!("{}{}", rng.word(1, 6), i))
+            .collect(),
+        children: vec![Vec::new(); count],
+        files: vec![Vec::new(); count],
+    };
+    for dir in 1..count {
+        let parent = rng.index(dir);
+        listing.children[parent].push(dir);
+    }
+
+    // Pick relative weights first, then scale them to the target total
+    let mut weights = Vec::new();
+    for dir in 0..count {
+        for _ in 0..rng.between(0, 4) {
+            weights.push((dir, rng.between(1, 1000) as u64));
+        }
+    }
+    if weights.is_empty() {
+        weights.push((0, 1));
+    }
+    let target = rng.between(45_000_000, 65_000_000) as u64;
+    let total: u64 = weights.iter().map(|(_, w)| w).sum();
+    for (dir, weight) in weights {
+        let name = format!("{}.{}", rng.word(1, 8), rng.word(1, 3));
+        listing.files[dir].push((name, (weight * target / total).max(1)));
+    }
+
+    let mut input = String::from("$ cd /\n");
+    write_dir(&listing, 0, &mut input);
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(7);
+        let sizes = Day07::parse(input).unwrap();
+        assert_eq!(
+            Day07::part_one(&sizes, &DiskParams::default()).unwrap(),
+            95437
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(7);
+        let sizes = Day07::parse(input).unwrap();
+        assert_eq!(
+            Day07::part_two(&sizes, &DiskParams::default()).unwrap(),
+            24933642
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn root_size_is_total_of_files(seed: u64, size in 1..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            let total: usize = input
+                .lines()
+                .filter_map(|line| line.split_once(' ')?.0.parse::<usize>().ok())
+                .sum();
+            prop_assert_eq!(Day07::parse(&input).unwrap()[""], total);
+        }
+
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 1..50usize) {
+            let sizes = Day07::parse(&generate(&mut Rng::new(seed), size)).unwrap();
+            prop_assert_eq!(Day07::parse(&serialize(&sizes)).unwrap(), sizes);
+        }
+    }
+}
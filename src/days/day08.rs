@@ -0,0 +1,103 @@
+use crate::{generate::Rng, grid::Grid, point::Vector, AocError, Solution};
+
+fn is_visible(trees: &Grid<u32>, x: usize, y: usize) -> bool {
+    let height = trees[(x, y)];
+    Vector::ORTHOGONAL
+        .into_iter()
+        .any(|direction| trees.ray(x, y, direction).all(|&tree| tree < height))
+}
+
+/// Product of the viewing distances, wide enough not to overflow on grids
+/// of any size that fits in memory
+fn score_tree(trees: &Grid<u32>, x: usize, y: usize) -> u64 {
+    let height = trees[(x, y)];
+    Vector::ORTHOGONAL
+        .into_iter()
+        .map(|direction| {
+            let mut count = 0;
+            for &tree in trees.ray(x, y, direction) {
+                count += 1;
+                if tree >= height {
+                    break;
+                }
+            }
+            count
+        })
+        .product()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Grid<u32>;
+    type Params = ();
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Grid<u32>, AocError> {
+        Grid::parse(Self::DAY, input, |c| {
+            c.to_digit(10).ok_or("Expected a digit")
+        })
+    }
+
+    fn part_one(trees: &Grid<u32>, _: &()) -> Result<usize, AocError> {
+        let visible_trees = trees
+            .iter()
+            .filter(|&((x, y), _)| is_visible(trees, x, y))
+            .count();
+        Ok(visible_trees)
+    }
+
+    fn part_two(trees: &Grid<u32>, _: &()) -> Result<u64, AocError> {
+        let max_score = trees
+            .iter()
+            .map(|((x, y), _)| score_tree(trees, x, y))
+            .max()
+            .unwrap_or_default();
+        Ok(max_score)
+    }
+}
+
+/// Random square grid of tree heights, `size` trees across
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        input.extend((0..size.max(1)).map(|_| (b'0' + rng.below(10) as u8) as char));
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(8);
+        assert_eq!(
+            Day08::part_one(&Day08::parse(input).unwrap(), &()).unwrap(),
+            21
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(8);
+        assert_eq!(
+            Day08::part_two(&Day08::parse(input).unwrap(), &()).unwrap(),
+            8
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(seed: u64, size in 1..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(Day08::parse(&input).unwrap().to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use crate::{generate::Rng, input, point::Vector, AocError, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct ParseDirectionError;
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse direction")
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(ParseDirectionError),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Up => write!(f, "U"),
+            Direction::Down => write!(f, "D"),
+            Direction::Left => write!(f, "L"),
+            Direction::Right => write!(f, "R"),
+        }
+    }
+}
+
+impl Direction {
+    fn step(&self) -> Vector {
+        match self {
+            Direction::Up => Vector::UP,
+            Direction::Down => Vector::DOWN,
+            Direction::Left => Vector::LEFT,
+            Direction::Right => Vector::RIGHT,
+        }
+    }
+}
+
+/// Moves a knot to stay touching the one in front of it
+fn follow(knot: &mut Vector, other: Vector) {
+    let distance = knot.chebyshev(other);
+
+    if distance < 2 {
+        return;
+    }
+
+    // Knots start out touching and the head only ever moves one step, so
+    // the knot in front can't have moved more than one step per axis away
+    // whatever the input is
+    debug_assert!(
+        distance == 2,
+        "Expected point being followed to only move by one"
+    );
+
+    *knot += (other - *knot).signum();
+}
+
+struct Rope {
+    knots: Vec<Vector>,
+    tail_visited: HashSet<Vector>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Rope {
+        Rope {
+            knots: vec![Vector::default(); length],
+            tail_visited: HashSet::new(),
+        }
+    }
+
+    fn move_head(&mut self, direction: &Direction) {
+        self.knots[0] += direction.step();
+
+        for i in 1..self.knots.len() {
+            let prev_knot = self.knots[i - 1];
+            follow(&mut self.knots[i], prev_knot);
+        }
+
+        let tail_position = self.knots[self.knots.len() - 1];
+        self.tail_visited.insert(tail_position);
+    }
+
+    fn tail_visited_count(&self) -> usize {
+        self.tail_visited.len()
+    }
+}
+
+fn perform_moves(moves: &[(Direction, u8)], rope: &mut Rope) {
+    for (direction, count) in moves {
+        for _ in 0..*count {
+            rope.move_head(direction);
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<(Direction, u8)>;
+    type Params = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        input::parse_lines(Self::DAY, input, |line| {
+            let (direction, count) = line
+                .split_once(' ')
+                .ok_or("Expected a direction and count")?;
+            let direction = direction.parse::<Direction>().map_err(|e| e.to_string())?;
+            let count = count.parse::<u8>().map_err(|e| e.to_string())?;
+            Ok::<_, String>((direction, count))
+        })
+    }
+
+    fn part_one(moves: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let mut rope = Rope::new(2);
+        perform_moves(moves, &mut rope);
+        Ok(rope.tail_visited_count())
+    }
+
+    fn part_two(moves: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let mut rope = Rope::new(10);
+        perform_moves(moves, &mut rope);
+        Ok(rope.tail_visited_count())
+    }
+}
+
+/// Animation of the rope, built with the `visualize` feature
+#[cfg(feature = "visualize")]
+mod animation {
+    use super::*;
+    use crate::{
+        grid::Grid,
+        visualize::{blend, Recorder, Rgb, Visualize},
+    };
+
+    /// Rope drawn inside a fixed window, so that frames all have the same size
+    struct Animation {
+        rope: Rope,
+        min: Vector,
+        max: Vector,
+    }
+
+    impl Visualize for Animation {
+        fn frame(&self) -> Grid<Rgb> {
+            let width = (self.max.x - self.min.x + 1) as usize;
+            let height = (self.max.y - self.min.y + 1) as usize;
+            let mut grid = Grid::new(width, height, [16, 16, 24]);
+            let cell = |p: Vector| ((p.x - self.min.x) as usize, (p.y - self.min.y) as usize);
+
+            for &visited in &self.rope.tail_visited {
+                grid[cell(visited)] = [90, 90, 110];
+            }
+            // Draw from the tail up so the head is always on top
+            let last = self.rope.knots.len() - 1;
+            for (i, &knot) in self.rope.knots.iter().enumerate().rev() {
+                grid[cell(knot)] = blend([255, 40, 40], [255, 230, 60], i as f64 / last as f64);
+            }
+            grid
+        }
+    }
+
+    /// Records the 10 knot rope of part two after every step of the head
+    pub fn visualize(moves: &[(Direction, u8)], recorder: &mut Recorder) -> Result<(), AocError> {
+        // Knots never leave the area the head has been through
+        let mut head = Vector::default();
+        let (mut min, mut max) = (head, head);
+        for (direction, count) in moves {
+            head += direction.step() * *count as isize;
+            min = Vector::new(min.x.min(head.x), min.y.min(head.y));
+            max = Vector::new(max.x.max(head.x), max.y.max(head.y));
+        }
+
+        let mut animation = Animation {
+            rope: Rope::new(10),
+            min,
+            max,
+        };
+        recorder.record(&animation)?;
+        for (direction, count) in moves {
+            for _ in 0..*count {
+                animation.rope.move_head(direction);
+                recorder.record(&animation)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "visualize")]
+pub use animation::visualize;
+
+/// List of motions, one per line
+pub fn serialize(moves: &[(Direction, u8)]) -> String {
+    moves
+        .iter()
+        .map(|(direction, count)| format!("{} {}\n", direction, count))
+        .collect()
+}
+
+/// Random list of `size` motions of the head
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['U', 'D', 'L', 'R']),
+                rng.between(1, 20)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{
+        collection::vec, prop_assert, prop_assert_eq, prop_oneof, proptest, strategy::Just,
+    };
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(9);
+        assert_eq!(
+            Day09::part_one(&Day09::parse(input).unwrap(), &()).unwrap(),
+            13
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(9, 2);
+        assert_eq!(
+            Day09::part_two(&Day09::parse(input).unwrap(), &()).unwrap(),
+            36
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn knots_stay_touching(
+            length in 2..=10usize,
+            moves in vec(
+                prop_oneof![
+                    Just(Direction::Up),
+                    Just(Direction::Down),
+                    Just(Direction::Left),
+                    Just(Direction::Right),
+                ],
+                0..200,
+            ),
+        ) {
+            let mut rope = Rope::new(length);
+            for direction in &moves {
+                rope.move_head(direction);
+                for pair in rope.knots.windows(2) {
+                    prop_assert!(pair[0].chebyshev(pair[1]) <= 1);
+                }
+            }
+        }
+
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 0..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day09::parse(&input).unwrap()), input);
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use core::fmt;
+use std::str::FromStr;
+
+use crate::{generate::Rng, input, AocError, Solution};
+
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+#[derive(Debug)]
+pub struct ParseInstructionError(String);
+
+impl fmt::Display for ParseInstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unable to parse instruction: {}", self.0)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseInstructionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "noop" {
+            return Ok(Instruction::Noop);
+        }
+
+        let parts: Vec<&str> = s.split(' ').collect();
+        if parts.len() == 2 && parts[0] == "addx" {
+            let v: i32 = parts[1]
+                .parse()
+                .map_err(|_| ParseInstructionError(String::from(s)))?;
+            return Ok(Instruction::Addx(v));
+        }
+
+        Err(ParseInstructionError(String::from(s)))
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Noop => write!(f, "noop"),
+            Instruction::Addx(v) => write!(f, "addx {}", v),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Processor {
+    // Wide enough that adding any input's worth of i32 values can't overflow
+    x: i64,
+    busy_remaining: u32,
+    signal_strength: i64,
+    current_instruction: Instruction,
+}
+
+impl Processor {
+    fn new() -> Self {
+        Processor {
+            x: 1,
+            busy_remaining: 0,
+            signal_strength: 0,
+            current_instruction: Instruction::Noop,
+        }
+    }
+
+    fn signal_strength(&self) -> i64 {
+        self.signal_strength
+    }
+
+    fn load_instruction(&mut self, instruction: Instruction) {
+        self.busy_remaining = match instruction {
+            Instruction::Noop => 0,
+            Instruction::Addx(_) => 1,
+        };
+        self.current_instruction = instruction;
+    }
+
+    fn execute_current_instruction(&mut self) {
+        match self.current_instruction {
+            Instruction::Noop => {} // Do nothing
+            Instruction::Addx(v) => self.x += v as i64,
+        }
+        self.current_instruction = Instruction::Noop;
+    }
+
+    /// Runs the program to the end, calling `on_cycle` with the sprite
+    /// position each time the CRT draws a pixel
+    fn run(
+        &mut self,
+        sample_points: Vec<u32>,
+        mut instructions: impl Iterator<Item = Instruction>,
+        crt: &mut Crt,
+        mut on_cycle: impl FnMut(&Crt, i64),
+    ) {
+        let mut cycle_number = 0;
+
+        let Some(first_instruction) = instructions.next() else {
+            return;
+        };
+        self.load_instruction(first_instruction);
+
+        loop {
+            cycle_number += 1;
+
+            // X during the cycle, before an instruction finishing in it applies
+            if sample_points.contains(&cycle_number) {
+                self.signal_strength += cycle_number as i64 * self.x;
+            }
+
+            crt.update(self.x);
+            on_cycle(crt, self.x);
+
+            if self.busy_remaining > 0 {
+                self.busy_remaining -= 1;
+                continue;
+            }
+
+            self.execute_current_instruction();
+
+            if let Some(next_instruction) = instructions.next() {
+                self.load_instruction(next_instruction);
+            } else {
+                return;
+            }
+        }
+    }
+}
+
+struct Crt {
+    cursor: u32,
+    width: u32,
+    height: u32,
+    pixels: Vec<char>,
+}
+
+impl Crt {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            cursor: 0,
+            width,
+            height,
+            pixels: vec!['.'; (width * height) as usize],
+        }
+    }
+
+    fn update(&mut self, sprite_position: i64) {
+        let x = (self.cursor % self.width) as i64;
+
+        // The sprite is three pixels wide, centred on the X register
+        let next_pixel = if (x - sprite_position).abs() <= 1 {
+            '#'
+        } else {
+            ' '
+        };
+
+        self.pixels[self.cursor as usize] = next_pixel;
+
+        // Update cursor position
+        self.cursor = (self.cursor + 1) % (self.width * self.height);
+    }
+}
+
+impl fmt::Display for Crt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.pixels.iter().enumerate() {
+            if i % self.width as usize == 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Instruction>;
+    type Params = ();
+    type PartOne = i64;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, AocError> {
+        input::parse_lines(Self::DAY, input, |l| l.parse::<Instruction>())
+    }
+
+    fn part_one(instructions: &Vec<Instruction>, _: &()) -> Result<i64, AocError> {
+        let mut crt = Crt::new(40, 6);
+        let mut processor = Processor::new();
+        let instructions = instructions.iter().cloned();
+        let sample_points = vec![20, 60, 100, 140, 180, 220];
+        processor.run(sample_points, instructions, &mut crt, |_, _| {});
+        Ok(processor.signal_strength())
+    }
+
+    fn part_two(instructions: &Vec<Instruction>, _: &()) -> Result<String, AocError> {
+        let mut crt = Crt::new(40, 6);
+        let mut processor = Processor::new();
+        let instructions = instructions.iter().cloned();
+        processor.run(Vec::new(), instructions, &mut crt, |_, _| {});
+        Ok(crt.to_string())
+    }
+}
+
+/// Drawing the CRT as the beam sweeps across it, which only the `visualize`
+/// feature builds
+#[cfg(feature = "visualize")]
+mod animation {
+    use super::*;
+    use crate::{
+        grid::Grid,
+        visualize::{Recorder, Rgb, Visualize},
+    };
+
+    /// CRT part way through drawing, along with where the sprite is
+    struct Beam<'a> {
+        crt: &'a Crt,
+        sprite: i64,
+    }
+
+    impl Visualize for Beam<'_> {
+        fn frame(&self) -> Grid<Rgb> {
+            let crt = self.crt;
+            let (width, height) = (crt.width as usize, crt.height as usize);
+            let mut grid = Grid::new(width, height, [0, 0, 0]);
+            for (i, &pixel) in crt.pixels.iter().enumerate() {
+                grid[(i % width, i / width)] = match pixel {
+                    '#' => [80, 255, 120],
+                    ' ' => [10, 40, 20],
+                    _ => [0, 0, 0],
+                };
+            }
+
+            // The cursor has already moved on from the pixel just drawn
+            let beam = (crt.cursor as usize + width * height - 1) % (width * height);
+            let (x, y) = (beam % width, beam / width);
+            for sprite_x in self.sprite..self.sprite + 3 {
+                if let Some(cell) = usize::try_from(sprite_x)
+                    .ok()
+                    .and_then(|sprite_x| grid.get_mut(sprite_x, y))
+                {
+                    cell[2] = 200;
+                }
+            }
+            grid[(x, y)] = [255, 255, 255];
+            grid
+        }
+    }
+
+    /// Records the CRT after every pixel the beam draws
+    pub fn visualize(
+        instructions: &[Instruction],
+        recorder: &mut Recorder,
+    ) -> Result<(), AocError> {
+        let mut crt = Crt::new(40, 6);
+        let mut result = Ok(());
+        Processor::new().run(
+            Vec::new(),
+            instructions.iter().cloned(),
+            &mut crt,
+            |crt, sprite| {
+                if result.is_ok() {
+                    result = recorder.record(&Beam { crt, sprite });
+                }
+            },
+        );
+        Ok(result?)
+    }
+}
+
+#[cfg(feature = "visualize")]
+pub use animation::visualize;
+
+/// Program with one instruction per line
+pub fn serialize(instructions: &[Instruction]) -> String {
+    instructions.iter().map(|i| format!("{}\n", i)).collect()
+}
+
+/// Random program of at least `size` instructions, long enough to draw the
+/// whole screen, that keeps the sprite on screen
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let (mut x, mut count, mut cycles) = (1i64, 0, 0);
+    while count < size || cycles < 240 {
+        if rng.one_in(3) {
+            input += "noop\n";
+            cycles += 1;
+        } else {
+            let v = rng.between((-x).max(-15), (39 - x).min(15));
+            input += &format!("addx {}\n", v);
+            x += v;
+            cycles += 2;
+        }
+        count += 1;
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(10);
+        assert_eq!(
+            Day10::part_one(&Day10::parse(input).unwrap(), &()).unwrap(),
+            13140
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(10);
+        let screen = Day10::part_two(&Day10::parse(input).unwrap(), &()).unwrap();
+        let expected = [
+            "##  ##  ##  ##  ##  ##  ##  ##  ##  ##  ",
+            "###   ###   ###   ###   ###   ###   ### ",
+            "####    ####    ####    ####    ####    ",
+            "#####     #####     #####     #####     ",
+            "######      ######      ######      ####",
+            "#######       #######       #######     ",
+        ];
+        assert_eq!(screen.lines().skip(1).collect::<Vec<_>>(), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 0..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day10::parse(&input).unwrap()), input);
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt,
+};
+
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, one_of, space0, space1},
+    combinator::map_opt,
+    multi::separated_list0,
+    sequence::{delimited, pair, preceded},
+    IResult,
+};
+
+use crate::{
+    generate::Rng,
+    parsing::{blocks_of, labelled, parse_all, unsigned},
+    AocError, Solution,
+};
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Add(u128),
+    Multiply(u128),
+    Square,
+}
+
+impl Operation {
+    /// New worry level, or None if it overflows
+    fn apply(&self, item: u128) -> Option<u128> {
+        match self {
+            Operation::Add(x) => item.checked_add(*x),
+            Operation::Multiply(x) => item.checked_mul(*x),
+            Operation::Square => item.checked_mul(item),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Add(x) => write!(f, "+ {}", x),
+            Operation::Multiply(x) => write!(f, "* {}", x),
+            Operation::Square => write!(f, "* old"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    inspections: Cell<u64>,
+    items: RefCell<VecDeque<u128>>,
+    operation: Operation,
+    divisor: u64,
+    throw_true: usize,
+    throw_false: usize,
+}
+
+fn monkey_id(input: &str) -> IResult<&str, u32> {
+    delimited(tag("Monkey "), unsigned, char(':'))(input)
+}
+
+fn starting_items(input: &str) -> IResult<&str, VecDeque<u128>> {
+    let item_parser = preceded(space0, unsigned);
+    let (input, items) =
+        labelled("Starting items:", separated_list0(char(','), item_parser))(input)?;
+    Ok((input, items.into()))
+}
+
+fn operation(input: &str) -> IResult<&str, Operation> {
+    labelled(
+        "Operation: new = old",
+        map_opt(
+            pair(one_of("*+"), preceded(space1, alt((tag("old"), digit1)))),
+            |(operator, value): (char, &str)| -> Option<Operation> {
+                match (operator, value) {
+                    ('+', x) => {
+                        let x = x.parse().ok()?;
+                        Some(Operation::Add(x))
+                    }
+                    ('*', "old") => Some(Operation::Square),
+                    ('*', x) => {
+                        let x = x.parse().ok()?;
+                        Some(Operation::Multiply(x))
+                    }
+                    (_, _) => None,
+                }
+            },
+        ),
+    )(input)
+}
+
+fn test(input: &str) -> IResult<&str, (u64, usize, usize)> {
+    let (input, divisor) = labelled("Test: divisible by", unsigned)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, true_monkey) = labelled("If true: throw to monkey", unsigned)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, false_monkey) = labelled("If false: throw to monkey", unsigned)(input)?;
+    Ok((input, (divisor, true_monkey, false_monkey)))
+}
+
+fn monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, _) = monkey_id(input)?;
+    let (input, items) = preceded(line_ending, starting_items)(input)?;
+    let (input, operation) = preceded(line_ending, operation)(input)?;
+    let (input, (divisor, throw_true, throw_false)) = preceded(line_ending, test)(input)?;
+
+    Ok((
+        input,
+        Monkey {
+            inspections: Cell::new(0),
+            items: RefCell::new(items),
+            operation,
+            divisor,
+            throw_true,
+            throw_false,
+        },
+    ))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type Params = ();
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, AocError> {
+        let monkeys = parse_all(Self::DAY, input, blocks_of(monkey))?;
+
+        for (index, monkey) in monkeys.iter().enumerate() {
+            if monkey.divisor == 0 {
+                let reason = format!("Monkey {} tests divisibility by zero", index);
+                return Err(AocError::solve(Self::DAY, reason));
+            }
+            for target in [monkey.throw_true, monkey.throw_false] {
+                if target >= monkeys.len() {
+                    let reason = format!("Monkey {} throws to missing monkey {}", index, target);
+                    return Err(AocError::solve(Self::DAY, reason));
+                }
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part_one(monkeys: &Vec<Monkey>, _: &()) -> Result<u64, AocError> {
+        let monkeys = monkeys.clone();
+
+        for _ in 0..20 {
+            for monkey in monkeys.iter() {
+                let mut items = monkey.items.take();
+                let inspection_count = monkey.inspections.take() + items.len() as u64;
+                monkey.inspections.set(inspection_count);
+
+                while let Some(item) = items.pop_front() {
+                    let worry_level = monkey.operation.apply(item).ok_or_else(overflow)? / 3;
+
+                    if worry_level.is_multiple_of(monkey.divisor as u128) {
+                        monkeys[monkey.throw_true]
+                            .items
+                            .borrow_mut()
+                            .push_back(worry_level);
+                    } else {
+                        monkeys[monkey.throw_false]
+                            .items
+                            .borrow_mut()
+                            .push_back(worry_level);
+                    }
+                }
+            }
+        }
+
+        monkey_business(monkeys)
+    }
+
+    fn part_two(monkeys: &Vec<Monkey>, _: &()) -> Result<u64, AocError> {
+        let monkeys = monkeys.clone();
+
+        // Worry levels only matter modulo each divisor, so keep them below
+        // the least common multiple of all of them
+        let mod_value = monkeys
+            .iter()
+            .try_fold(1u128, |acc, m| {
+                let divisor = m.divisor as u128;
+                (acc / gcd(acc, divisor)).checked_mul(divisor)
+            })
+            .ok_or_else(overflow)?;
+
+        for _ in 0..10000 {
+            for monkey in monkeys.iter() {
+                let mut items = monkey.items.take();
+                let inspection_count = monkey.inspections.take() + items.len() as u64;
+                monkey.inspections.set(inspection_count);
+
+                while let Some(item) = items.pop_front() {
+                    let worry_level =
+                        monkey.operation.apply(item).ok_or_else(overflow)? % mod_value;
+
+                    if worry_level.is_multiple_of(monkey.divisor as u128) {
+                        monkeys[monkey.throw_true]
+                            .items
+                            .borrow_mut()
+                            .push_back(worry_level);
+                    } else {
+                        monkeys[monkey.throw_false]
+                            .items
+                            .borrow_mut()
+                            .push_back(worry_level);
+                    }
+                }
+            }
+        }
+
+        monkey_business(monkeys)
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn overflow() -> AocError {
+    AocError::solve(Day11::DAY, "Worry level overflowed")
+}
+
+/// Product of the inspection counts of the two most active monkeys
+fn monkey_business(monkeys: Vec<Monkey>) -> Result<u64, AocError> {
+    monkeys
+        .into_iter()
+        .map(|m| m.inspections.take())
+        .sorted()
+        .rev()
+        .take(2)
+        .try_fold(1u64, |acc, count| acc.checked_mul(count))
+        .ok_or_else(|| AocError::solve(Day11::DAY, "Monkey business overflowed"))
+}
+
+/// Notes on each monkey, numbered in order
+pub fn serialize(monkeys: &[Monkey]) -> String {
+    monkeys
+        .iter()
+        .enumerate()
+        .map(|(id, monkey)| {
+            format!(
+                "Monkey {}:\n  Starting items:{}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                id,
+                monkey.items.borrow().iter().map(|item| format!(" {}", item)).join(","),
+                monkey.operation,
+                monkey.divisor,
+                monkey.throw_true,
+                monkey.throw_false
+            )
+        })
+        .join("\n")
+}
+
+/// Random group of `size` monkeys whose worry levels stay small enough to
+/// solve both parts
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // Wild operations can overflow part one's worry levels, so fall back to
+    // ones that barely change them if a few attempts fail
+    for attempt in 0.. {
+        let monkeys = monkeys(rng, size.max(2), attempt >= 5);
+        if Day11::part_one(&monkeys, &()).is_ok() {
+            return serialize(&monkeys);
+        }
+    }
+    unreachable!()
+}
+
+fn monkeys(rng: &mut Rng, count: usize, gentle: bool) -> Vec<Monkey> {
+    const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+    let square = rng.index(count);
+
+    (0..count)
+        .map(|id| {
+            let items = (0..rng.between(0, 6))
+                .map(|_| rng.between(50, 99) as u128)
+                .collect();
+            let operation = if !gentle && id == square && rng.one_in(2) {
+                Operation::Square
+            } else if rng.one_in(2) {
+                Operation::Add(rng.between(1, 8) as u128)
+            } else if gentle {
+                Operation::Multiply(rng.between(2, 3) as u128)
+            } else {
+                Operation::Multiply(rng.between(2, 19) as u128)
+            };
+            let mut target = || (id + 1 + rng.index(count - 1)) % count;
+            Monkey {
+                inspections: Cell::new(0),
+                items: RefCell::new(items),
+                operation,
+                throw_true: target(),
+                throw_false: target(),
+                divisor: *rng.choose(&PRIMES),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(11);
+        assert_eq!(
+            Day11::part_one(&Day11::parse(input).unwrap(), &()).unwrap(),
+            10605
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(11);
+        assert_eq!(
+            Day11::part_two(&Day11::parse(input).unwrap(), &()).unwrap(),
+            2713310158
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 2..20usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day11::parse(&input).unwrap()), input);
+        }
+    }
+}
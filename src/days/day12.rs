@@ -0,0 +1,292 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{generate::Rng, grid::Grid, AocError, Solution};
+
+#[derive(Debug, PartialEq)]
+enum Square {
+    Start,
+    End,
+    Level(u32),
+}
+
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Square::Start => write!(f, "S"),
+            Square::End => write!(f, "E"),
+            Square::Level(level) => write!(f, "{}", (b'a' + *level as u8) as char),
+        }
+    }
+}
+
+pub struct HeightMap {
+    grid: Grid<Square>,
+}
+
+impl HeightMap {
+    fn parse_input(input: &str) -> Result<HeightMap, AocError> {
+        let grid = Grid::parse(Day12::DAY, input, |c| match c {
+            'S' => Ok(Square::Start),
+            'E' => Ok(Square::End),
+            c if c.is_ascii_lowercase() => Ok(Square::Level(c as u32 - 'a' as u32)),
+            _ => Err(format!("Unexpected square value {:?}", c)),
+        })?;
+        let height_map = HeightMap { grid };
+
+        if height_map.find(&Square::Start).is_none() {
+            return Err(AocError::solve(Day12::DAY, "No start square"));
+        }
+        if height_map.find(&Square::End).is_none() {
+            return Err(AocError::solve(Day12::DAY, "No end square"));
+        }
+        Ok(height_map)
+    }
+
+    fn find(&self, square: &Square) -> Option<(usize, usize)> {
+        self.grid.position(|s| s == square)
+    }
+
+    fn find_start(&self) -> (usize, usize) {
+        self.find(&Square::Start).expect("checked when parsing")
+    }
+
+    fn find_end(&self) -> (usize, usize) {
+        self.find(&Square::End).expect("checked when parsing")
+    }
+
+    fn find_a(&self) -> Vec<(usize, usize)> {
+        self.grid
+            .iter()
+            .filter(|(_, square)| **square == Square::Level(0))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    fn get_height(&self, x: usize, y: usize) -> Option<u32> {
+        match self.grid.get(x, y)? {
+            Square::Start => Some(0),
+            Square::End => Some(26),
+            Square::Level(level) => Some(*level),
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = HeightMap;
+    type Params = ();
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<HeightMap, AocError> {
+        HeightMap::parse_input(input)
+    }
+
+    fn part_one(height_map: &HeightMap, _: &()) -> Result<u32, AocError> {
+        let mut squares_reached = HashMap::new();
+
+        let (start_x, start_y) = height_map.find_start();
+        let mut to_process = vec![(start_x, start_y, 0)];
+
+        while let Some((x, y, length)) = to_process.pop() {
+            squares_reached.insert((x, y), length);
+            let height = height_map.get_height(x, y).unwrap();
+
+            for (x, y) in height_map.grid.neighbours(x, y) {
+                let target_height = height_map.get_height(x, y).unwrap();
+                if target_height <= height + 1 {
+                    let prev_length = squares_reached.get(&(x, y)).unwrap_or(&u32::MAX);
+                    if length + 1 < *prev_length {
+                        to_process.push((x, y, length + 1));
+                    }
+                }
+            }
+        }
+
+        squares_reached
+            .get(&height_map.find_end())
+            .copied()
+            .ok_or_else(|| AocError::solve(Self::DAY, "Did not reach end square"))
+    }
+
+    fn part_two(height_map: &HeightMap, _: &()) -> Result<u32, AocError> {
+        let mut squares_reached = HashMap::new();
+
+        let mut to_process: Vec<_> = height_map
+            .find_a()
+            .into_iter()
+            .map(|(x, y)| (x, y, 0))
+            .collect();
+
+        while let Some((x, y, length)) = to_process.pop() {
+            squares_reached.insert((x, y), length);
+            let height = height_map.get_height(x, y).unwrap();
+
+            for (x, y) in height_map.grid.neighbours(x, y) {
+                let target_height = height_map.get_height(x, y).unwrap();
+                if target_height <= height + 1 {
+                    let prev_length = squares_reached.get(&(x, y)).unwrap_or(&u32::MAX);
+                    if length + 1 < *prev_length {
+                        to_process.push((x, y, length + 1));
+                    }
+                }
+            }
+        }
+
+        squares_reached
+            .get(&height_map.find_end())
+            .copied()
+            .ok_or_else(|| AocError::solve(Self::DAY, "Did not reach end square"))
+    }
+}
+
+/// Search animation, which needs the `visualize` feature
+#[cfg(feature = "visualize")]
+mod animation {
+    use super::*;
+    use crate::visualize::{blend, Recorder, Rgb, Visualize};
+
+    /// Breadth first search from the start, one layer of squares at a time
+    struct Search<'a> {
+        height_map: &'a HeightMap,
+        reached: Grid<bool>,
+        frontier: Vec<(usize, usize)>,
+    }
+
+    impl Search<'_> {
+        /// Moves on to every square one more step from the start, returning
+        /// false once there are none left
+        fn expand(&mut self) -> bool {
+            let mut next = Vec::new();
+            for &(x, y) in &self.frontier {
+                let height = self.height_map.get_height(x, y).unwrap();
+                for (x, y) in self.height_map.grid.neighbours(x, y) {
+                    let target_height = self.height_map.get_height(x, y).unwrap();
+                    if target_height <= height + 1 && !self.reached[(x, y)] {
+                        self.reached[(x, y)] = true;
+                        next.push((x, y));
+                    }
+                }
+            }
+            self.frontier = next;
+            !self.frontier.is_empty()
+        }
+    }
+
+    impl Visualize for Search<'_> {
+        fn frame(&self) -> Grid<Rgb> {
+            let grid = &self.height_map.grid;
+            let mut frame = Grid::new(grid.width(), grid.height(), [0, 0, 0]);
+            for ((x, y), _) in grid.iter() {
+                let height = self.height_map.get_height(x, y).unwrap() as f64 / 26.0;
+                let colour = blend([20, 60, 30], [230, 230, 220], height);
+                frame[(x, y)] = if self.reached[(x, y)] {
+                    blend(colour, [40, 120, 255], 0.6)
+                } else {
+                    colour
+                };
+            }
+            for &position in &self.frontier {
+                frame[position] = [255, 200, 40];
+            }
+            frame[self.height_map.find_start()] = [255, 40, 40];
+            frame[self.height_map.find_end()] = [255, 40, 255];
+            frame
+        }
+    }
+
+    /// Records each layer of a breadth first search from the start, stopping
+    /// once it reaches the end
+    pub fn visualize(height_map: &HeightMap, recorder: &mut Recorder) -> Result<(), AocError> {
+        let start = height_map.find_start();
+        let end = height_map.find_end();
+        let grid = &height_map.grid;
+        let mut search = Search {
+            height_map,
+            reached: Grid::new(grid.width(), grid.height(), false),
+            frontier: vec![start],
+        };
+        search.reached[start] = true;
+
+        recorder.record(&search)?;
+        while !search.reached[end] && search.expand() {
+            recorder.record(&search)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "visualize")]
+pub use animation::visualize;
+
+/// Random heightmap at least `size` squares across, with a climbable path
+/// from the start along the middle row and down the right edge to the end
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (size.max(30), size.max(3));
+    let (start_y, end_y) = (rng.index(height), rng.index(height));
+    let mut rows: Vec<Vec<char>> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let path: Vec<(usize, usize)> = (0..width)
+        .map(|x| (x, start_y))
+        .chain(if start_y <= end_y {
+            (start_y + 1..=end_y)
+                .map(|y| (width - 1, y))
+                .collect::<Vec<_>>()
+        } else {
+            (end_y..start_y).rev().map(|y| (width - 1, y)).collect()
+        })
+        .collect();
+    // Heights rise from a to z by at most one each step, leaving one more step
+    // up to E
+    let steps = path.len() - 2;
+    for (k, &(x, y)) in path.iter().enumerate() {
+        rows[y][x] = (b'a' + (25 * k / steps).min(25) as u8) as char;
+    }
+    rows[start_y][0] = 'S';
+    rows[end_y][width - 1] = 'E';
+
+    rows.into_iter()
+        .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(12);
+        assert_eq!(
+            Day12::part_one(&Day12::parse(input).unwrap(), &()).unwrap(),
+            31
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(12);
+        assert_eq!(
+            Day12::part_two(&Day12::parse(input).unwrap(), &()).unwrap(),
+            29
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(seed: u64, size in 1..40usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(Day12::parse(&input).unwrap().grid.to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::{cmp::Ordering, fmt, slice};
+
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    character::complete::{char, line_ending},
+    combinator::map,
+    error::{Error, ErrorKind},
+    multi::separated_list0,
+    sequence::{separated_pair, terminated},
+    IResult,
+};
+
+use crate::{
+    generate::Rng,
+    parsing::{blocks_of, parse_all, unsigned},
+    AocError, Solution,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Item {
+    Single(u32),
+    List(Vec<Item>),
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Item::Single(value) => write!(f, "{}", value),
+            Item::List(items) => write!(f, "[{}]", items.iter().join(",")),
+        }
+    }
+}
+
+/// Deepest nesting of lists accepted, far beyond any real packet, so that
+/// parsing hostile input can't overflow the stack
+const MAX_DEPTH: usize = 100;
+
+fn list_or_single(input: &str, depth: usize) -> IResult<&str, Item> {
+    let (input, item) = alt((|input| nested_list(input, depth), single))(input)?;
+    Ok((input, item))
+}
+
+fn single(input: &str) -> IResult<&str, Item> {
+    map(unsigned, Item::Single)(input)
+}
+
+fn list(input: &str) -> IResult<&str, Item> {
+    nested_list(input, 1)
+}
+
+/// List inside `depth - 1` others
+fn nested_list(input: &str, depth: usize) -> IResult<&str, Item> {
+    let (input, _) = char('[')(input)?;
+    if depth > MAX_DEPTH {
+        return Err(nom::Err::Failure(Error::new(input, ErrorKind::TooLarge)));
+    }
+    let (input, list_items) = terminated(
+        separated_list0(char(','), |input| list_or_single(input, depth + 1)),
+        char(']'),
+    )(input)?;
+    Ok((input, Item::List(list_items)))
+}
+
+fn packet_pair(input: &str) -> IResult<&str, (Item, Item)> {
+    separated_pair(list, line_ending, list)(input)
+}
+
+#[derive(Debug, PartialEq)]
+enum Comparison {
+    Ordered,
+    Unordered,
+    Same,
+}
+
+fn compare_items(left: &Item, right: &Item) -> Comparison {
+    // Rest of each pair of lists still to compare, outermost first, kept on
+    // the heap rather than recursing so nesting can't overflow the stack
+    let mut lists: Vec<(&[Item], &[Item])> = Vec::new();
+    let mut pair = (left, right);
+    loop {
+        match pair {
+            (Item::Single(l), Item::Single(r)) => match l.cmp(r) {
+                Ordering::Less => return Comparison::Ordered,
+                Ordering::Equal => {}
+                Ordering::Greater => return Comparison::Unordered,
+            },
+            (l @ Item::Single(_), Item::List(r)) => lists.push((slice::from_ref(l), r)),
+            (Item::List(l), r @ Item::Single(_)) => lists.push((l, slice::from_ref(r))),
+            (Item::List(l), Item::List(r)) => lists.push((l, r)),
+        }
+
+        // Move on to the next pair of items, finishing any lists that have
+        // run out on both sides
+        pair = loop {
+            let Some((l, r)) = lists.last_mut() else {
+                return Comparison::Same;
+            };
+            match (l.split_first(), r.split_first()) {
+                (Some((l_first, l_rest)), Some((r_first, r_rest))) => {
+                    (*l, *r) = (l_rest, r_rest);
+                    break (l_first, r_first);
+                }
+                (None, Some(_)) => return Comparison::Ordered,
+                (Some(_), None) => return Comparison::Unordered,
+                (None, None) => {
+                    lists.pop();
+                }
+            }
+        };
+    }
+}
+
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match compare_items(self, other) {
+            Comparison::Ordered => Ordering::Less,
+            Comparison::Unordered => Ordering::Greater,
+            Comparison::Same => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<(Item, Item)>;
+    type Params = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        parse_all(Self::DAY, input, blocks_of(packet_pair))
+    }
+
+    fn part_one(packet_pairs: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let mut ordered_indexes = Vec::new();
+
+        for (index, (left, right)) in packet_pairs.iter().enumerate() {
+            if compare_items(left, right) != Comparison::Unordered {
+                ordered_indexes.push(index + 1);
+            }
+        }
+
+        Ok(ordered_indexes.into_iter().sum())
+    }
+
+    fn part_two(packet_pairs: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let (_, first_distress) = list("[[2]]").unwrap();
+        let (_, second_distress) = list("[[6]]").unwrap();
+
+        let mut packets: Vec<Item> = packet_pairs
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect();
+
+        packets.push(first_distress.clone());
+        packets.push(second_distress.clone());
+        packets.sort();
+
+        let first = packets.iter().position(|i| *i == first_distress).unwrap() + 1;
+        let second = packets.iter().position(|i| *i == second_distress).unwrap() + 1;
+        Ok(first * second)
+    }
+}
+
+/// Pairs of packets separated by blank lines
+pub fn serialize(packet_pairs: &[(Item, Item)]) -> String {
+    packet_pairs
+        .iter()
+        .map(|(left, right)| format!("{}\n{}\n", left, right))
+        .join("\n")
+}
+
+/// Random list of `size` pairs of packets
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    fn packet(rng: &mut Rng, depth: usize) -> String {
+        let items = (0..rng.between(0, 4))
+            .map(|_| {
+                if depth < 3 && rng.one_in(3) {
+                    packet(rng, depth + 1)
+                } else {
+                    rng.between(0, 10).to_string()
+                }
+            })
+            .join(",");
+        format!("[{}]", items)
+    }
+
+    (0..size.max(1))
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{collection::vec, prop_assert, prop_assert_eq, proptest, strategy::Strategy};
+
+    fn item() -> impl Strategy<Value = Item> {
+        (0..=10u32)
+            .prop_map(Item::Single)
+            .prop_recursive(3, 32, 4, |inner| vec(inner, 0..4).prop_map(Item::List))
+    }
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(13);
+        assert_eq!(
+            Day13::part_one(&Day13::parse(input).unwrap(), &()).unwrap(),
+            13
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(13);
+        assert_eq!(
+            Day13::part_two(&Day13::parse(input).unwrap(), &()).unwrap(),
+            140
+        );
+    }
+
+    #[test]
+    fn deeply_nested_packet_is_an_error() {
+        let packet = "[".repeat(100_000);
+        let err = Day13::parse(&format!("{}\n[]\n", packet)).unwrap_err();
+        assert!(matches!(err, AocError::Parse { line: 1, .. }), "{}", err);
+
+        let deepest = format!("{}{}", "[".repeat(MAX_DEPTH), "]".repeat(MAX_DEPTH));
+        let pairs = Day13::parse(&format!("{}\n{}\n", deepest, deepest)).unwrap();
+        assert_eq!(Day13::part_one(&pairs, &()).unwrap(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn ordering_is_antisymmetric(a in item(), b in item()) {
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+        }
+
+        #[test]
+        fn ordering_is_transitive(a in item(), b in item(), c in item()) {
+            if a <= b && b <= c {
+                prop_assert!(a <= c);
+            }
+            if a >= b && b >= c {
+                prop_assert!(a >= c);
+            }
+        }
+
+        #[test]
+        fn sorting_orders_every_pair(mut items in vec(item(), 0..20)) {
+            items.sort();
+            for (i, a) in items.iter().enumerate() {
+                for b in &items[i..] {
+                    prop_assert!(a <= b);
+                }
+            }
+        }
+
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 1..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day13::parse(&input).unwrap()), input);
+        }
+    }
+}
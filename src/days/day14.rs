@@ -0,0 +1,320 @@
+use std::fmt::{self, Display};
+
+use itertools::Itertools;
+use nom::{bytes::complete::tag, multi::separated_list1, IResult};
+
+use crate::{
+    generate::Rng,
+    grid::Grid,
+    parsing::{lines_of, parse_all, point},
+    point::{BoundingBox, Point, Vector},
+    AocError, Solution,
+};
+
+/// Largest coordinate accepted, as the grid grows with the square of it
+const MAX_COORDINATE: usize = 1000;
+
+fn path(input: &str) -> IResult<&str, Vec<Point<usize>>> {
+    separated_list1(tag(" -> "), point)(input)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Material {
+    Rock,
+    Air,
+    Sand,
+}
+
+impl Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Material::Rock => write!(f, "#")?,
+            Material::Air => write!(f, " ")?,
+            Material::Sand => write!(f, "o")?,
+        };
+        Ok(())
+    }
+}
+
+/// Where sand pours in from
+const SOURCE: Point<usize> = Point::new(500, 0);
+
+struct RockFace {
+    grid: Grid<Material>,
+    /// How far right of its position in the scan each square is stored, so
+    /// sand spreading left of x = 0 still lands in the grid
+    shift: usize,
+    floor: usize,
+}
+
+impl RockFace {
+    fn new(paths: &[Vec<Point<usize>>]) -> Self {
+        let bounds = BoundingBox::of(paths.iter().flatten().copied())
+            .unwrap_or(BoundingBox::new(SOURCE, SOURCE))
+            .including(SOURCE);
+        let floor = bounds.max.y;
+
+        // Sand can pile up to one square above the floor and spread sideways
+        // at most that far from where it enters
+        let shift = floor + 2;
+        let mut grid = Grid::new(bounds.max.x + 2 * shift + 1, floor + 2, Material::Air);
+
+        for path in paths {
+            for (start, stop) in path.iter().tuple_windows() {
+                let x_range = if start.x < stop.x {
+                    start.x..=stop.x
+                } else {
+                    stop.x..=start.x
+                };
+
+                let y_range = if start.y < stop.y {
+                    start.y..=stop.y
+                } else {
+                    stop.y..=start.y
+                };
+
+                for x in x_range {
+                    for y in y_range.clone() {
+                        grid[(x + shift, y)] = Material::Rock;
+                    }
+                }
+            }
+        }
+
+        Self { grid, shift, floor }
+    }
+
+    fn get(&self, point: Point<usize>) -> &Material {
+        self.grid.get(point.x, point.y).unwrap_or(&Material::Air)
+    }
+
+    /// Returns whether the sand came to rest
+    fn drop_sand(&mut self, bottomless: bool) -> bool {
+        let mut sand = Point::new(SOURCE.x + self.shift, SOURCE.y);
+
+        // Check if we've filled up to the top
+        if *self.get(sand) == Material::Sand {
+            return false;
+        }
+
+        'falling: while sand.y <= self.floor {
+            for step in [
+                Vector::DOWN,
+                Vector::DOWN + Vector::LEFT,
+                Vector::DOWN + Vector::RIGHT,
+            ] {
+                let next = sand.offset(step).expect("grid is wide enough");
+                if *self.get(next) == Material::Air {
+                    sand = next;
+                    continue 'falling;
+                }
+            }
+            break;
+        }
+
+        if bottomless && sand.y > self.floor {
+            return false;
+        }
+        self.grid[(sand.x, sand.y)] = Material::Sand;
+        true
+    }
+}
+
+impl Display for RockFace {
+    /// Draws only the columns that contain rock or sand
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let occupied = self
+            .grid
+            .iter()
+            .filter(|(_, material)| **material != Material::Air)
+            .map(|((x, _), _)| x);
+        let Some((min_x, max_x)) = occupied.minmax().into_option() else {
+            return Ok(());
+        };
+
+        for y in 0..self.grid.height() {
+            for material in self.grid.row(y).take(max_x + 1).skip(min_x) {
+                write!(f, "{}", material)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Frames of sand filling the cave, only with the `visualize` feature
+#[cfg(feature = "visualize")]
+mod animation {
+    use super::*;
+    use crate::visualize::{Recorder, Rgb, Visualize};
+
+    impl Visualize for RockFace {
+        /// Draws the columns the pile of sand can spread across
+        fn frame(&self) -> Grid<Rgb> {
+            let min_x = SOURCE.x;
+            let width = 2 * self.shift + 1;
+            let mut frame = Grid::new(width, self.grid.height(), [20, 20, 40]);
+            for y in 0..self.grid.height() {
+                for (x, material) in self.grid.row(y).skip(min_x).take(width).enumerate() {
+                    frame[(x, y)] = match material {
+                        Material::Rock => [120, 110, 100],
+                        Material::Air => [20, 20, 40],
+                        Material::Sand => [230, 190, 90],
+                    };
+                }
+            }
+            frame[(self.shift, SOURCE.y)] = [255, 80, 40];
+            frame
+        }
+    }
+
+    /// Records the sand of part two piling up, one grain at a time
+    pub fn visualize(paths: &[Vec<Point<usize>>], recorder: &mut Recorder) -> Result<(), AocError> {
+        let mut rock_face = RockFace::new(paths);
+        recorder.record(&rock_face)?;
+        while rock_face.drop_sand(false) {
+            recorder.record(&rock_face)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "visualize")]
+pub use animation::visualize;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<Vec<Point<usize>>>;
+    type Params = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let paths = parse_all(Self::DAY, input, lines_of(path))?;
+        for (index, (line, path)) in input.lines().zip(&paths).enumerate() {
+            if path
+                .iter()
+                .any(|p| p.x > MAX_COORDINATE || p.y > MAX_COORDINATE)
+            {
+                return Err(AocError::parse(
+                    Self::DAY,
+                    index + 1,
+                    line,
+                    "Coordinate is out of range",
+                ));
+            }
+        }
+        Ok(paths)
+    }
+
+    fn part_one(paths: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let mut rock_face = RockFace::new(paths);
+
+        let mut count = 0;
+        while rock_face.drop_sand(true) {
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn part_two(paths: &Self::Input, _: &()) -> Result<usize, AocError> {
+        let mut rock_face = RockFace::new(paths);
+
+        let mut count = 0;
+        while rock_face.drop_sand(false) {
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Scan with one rock path per line
+pub fn serialize(paths: &[Vec<Point<usize>>]) -> String {
+    paths
+        .iter()
+        .map(|path| path.iter().join(" -> ") + "\n")
+        .collect()
+}
+
+/// Random scan of `size` rock paths, each made of up to four horizontal and
+/// vertical lines below and to either side of the source
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // Keep every coordinate within what `parse` accepts
+    let spread = (10 + size.min(MAX_COORDINATE)).min(MAX_COORDINATE - SOURCE.x) as i64;
+    let x = |rng: &mut Rng| rng.between(500 - spread, 500 + spread).max(0);
+    let y = |rng: &mut Rng| rng.between(2, spread);
+
+    (0..size.max(1))
+        .map(|_| {
+            let mut point = Point::new(x(rng), y(rng));
+            let mut points = vec![point];
+            let horizontal = rng.one_in(2);
+            for segment in 0..rng.between(1, 4) {
+                if (segment % 2 == 0) == horizontal {
+                    point.x = x(rng);
+                } else {
+                    point.y = y(rng);
+                }
+                if points.last() != Some(&point) {
+                    points.push(point);
+                }
+            }
+            points.iter().join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(
+            Day14::part_one(&Day14::parse(example!(14)).unwrap(), &()).unwrap(),
+            24
+        );
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(
+            Day14::part_two(&Day14::parse(example!(14)).unwrap(), &()).unwrap(),
+            93
+        );
+    }
+
+    #[test]
+    fn sand_spreads_left_of_zero() {
+        // The pile is 602 rows deep so reaches x = -101, less the one rock
+        let paths = Day14::parse("0,600 -> 0,600").unwrap();
+        assert_eq!(Day14::part_two(&paths, &()).unwrap(), 602 * 602 - 1);
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 1..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day14::parse(&input).unwrap()), input);
+        }
+    }
+
+    #[test]
+    fn large_generated_input_parses() {
+        let input = generate(&mut Rng::new(0), 2000);
+        assert!(Day14::parse(&input).is_ok());
+    }
+
+    #[test]
+    fn far_away_rock_is_an_error() {
+        let err = Day14::parse("498,4 -> 498,6\n5000,9 -> 5000,10\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 14, line 2: Coordinate is out of range in \"5000,9 -> 5000,10\""
+        );
+    }
+}
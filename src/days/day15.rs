@@ -0,0 +1,272 @@
+use std::fmt;
+
+use itertools::Itertools;
+use nom::{bytes::complete::tag, sequence::preceded, IResult};
+
+use crate::{
+    generate::Rng,
+    parsing::{lines_of, parse_all, xy_point},
+    point::{Point, Vector},
+    AocError, Solution,
+};
+
+#[derive(Debug)]
+pub struct Sensor {
+    location: Point<i64>,
+    beacon: Point<i64>,
+    distance: i64,
+}
+
+impl fmt::Display for Sensor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            self.location.x, self.location.y, self.beacon.x, self.beacon.y
+        )
+    }
+}
+
+/// Largest distance from the origin accepted in either direction, so sums of
+/// coordinates and distances can't overflow
+const MAX_COORDINATE: i64 = 1 << 40;
+
+impl Sensor {
+    fn new(location: Point<i64>, beacon: Point<i64>) -> Self {
+        Self {
+            location,
+            beacon,
+            distance: location.manhattan(beacon),
+        }
+    }
+}
+
+fn sensor(input: &str) -> IResult<&str, (Point<i64>, Point<i64>)> {
+    let (input, location) = preceded(tag("Sensor at "), xy_point)(input)?;
+    let (input, beacon) = preceded(tag(": closest beacon is at "), xy_point)(input)?;
+    Ok((input, (location, beacon)))
+}
+
+pub struct SearchParams {
+    /// Row to count positions without a beacon in
+    pub row: i64,
+    /// Largest x and y coordinate the distress beacon can be at
+    pub max: i64,
+}
+
+impl Default for SearchParams {
+    fn default() -> Self {
+        Self {
+            row: 2000000,
+            max: 4000000,
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Sensor>;
+    type Params = SearchParams;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Vec<Sensor>, AocError> {
+        let pairs = parse_all(Self::DAY, input, lines_of(sensor))?;
+        input
+            .lines()
+            .zip(pairs)
+            .enumerate()
+            .map(|(index, (line, (location, beacon)))| {
+                let in_range =
+                    |p: Point<i64>| p.x.abs() <= MAX_COORDINATE && p.y.abs() <= MAX_COORDINATE;
+                if !in_range(location) || !in_range(beacon) {
+                    return Err(AocError::parse(
+                        Self::DAY,
+                        index + 1,
+                        line,
+                        "Coordinate is out of range",
+                    ));
+                }
+                Ok(Sensor::new(location, beacon))
+            })
+            .collect()
+    }
+
+    fn part_one(sensors: &Vec<Sensor>, params: &SearchParams) -> Result<usize, AocError> {
+        let row = params.row;
+        let mut ranges: Vec<(i64, i64)> = sensors
+            .iter()
+            .filter_map(|sensor| {
+                let dy = (row - sensor.location.y).abs();
+                let dx = sensor.distance - dy;
+                (dy < sensor.distance).then_some((sensor.location.x - dx, sensor.location.x + dx))
+            })
+            .collect();
+        ranges.sort();
+
+        // Merge overlapping ranges so no position is counted twice
+        let mut merged: Vec<(i64, i64)> = Vec::new();
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some((_, last_end)) if start <= *last_end + 1 => *last_end = (*last_end).max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        let beacons = sensors
+            .iter()
+            .map(|sensor| sensor.beacon)
+            .filter(|beacon| {
+                beacon.y == row
+                    && merged
+                        .iter()
+                        .any(|(start, end)| (*start..=*end).contains(&beacon.x))
+            })
+            .unique()
+            .count();
+        let covered: i64 = merged.iter().map(|(start, end)| end - start + 1).sum();
+        Ok(covered as usize - beacons)
+    }
+
+    fn part_two(sensors: &Vec<Sensor>, params: &SearchParams) -> Result<i64, AocError> {
+        // Scan each row, jumping past the end of whichever sensor covers the
+        // current position, so the time taken doesn't depend on how far apart
+        // the sensors are
+        for y in 0..=params.max {
+            let mut x = 0;
+            while x <= params.max {
+                let point = Point::new(x, y);
+                match sensors
+                    .iter()
+                    .find(|sensor| sensor.location.manhattan(point) <= sensor.distance)
+                {
+                    Some(sensor) => {
+                        let dy = (sensor.location.y - y).abs();
+                        x = sensor.location.x + sensor.distance - dy + 1;
+                    }
+                    None => return Ok(x * 4000000 + y),
+                }
+            }
+        }
+        Err(AocError::solve(Self::DAY, "Distress beacon not found"))
+    }
+}
+
+/// List of sensors, one per line
+pub fn serialize(sensors: &[Sensor]) -> String {
+    sensors.iter().map(|s| format!("{}\n", s)).collect()
+}
+
+/// Random sensors covering the whole search area but one point, about `size`
+/// of them
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    generate_with(rng, size, &SearchParams::default())
+}
+
+/// Random sensors covering all of `params`' search area except one point
+///
+/// Sensors of range `r` centred `r` apart diagonally tile the plane like a
+/// chessboard, so leaving out the tile on the distress beacon and stretching
+/// its four diagonal neighbours to cover all of it but the middle leaves that
+/// one point uncovered
+pub fn generate_with(rng: &mut Rng, size: usize, params: &SearchParams) -> String {
+    let max = params.max;
+    let r = ((max as f64 / (2.0 * size.max(1) as f64).sqrt()) as i64).max(2);
+    let distress = Point::new(rng.between(0, max), rng.between(0, max));
+
+    // Tiles whose centres are close enough to the search area to cover part
+    // of it
+    let steps = |c: i64| -((c + r) / r)..=(max + r - c) / r;
+    let mut tiles: Vec<Point<i64>> = steps(distress.x)
+        .flat_map(|p| steps(distress.y).map(move |q| Point::new(p, q)))
+        .filter(|t| (t.x + t.y) % 2 == 0 && *t != Point::new(0, 0))
+        .collect();
+    rng.shuffle(&mut tiles);
+
+    let centre = |tile: Point<i64>| distress + tile * r;
+    let stretched = [(1, 1), (1, -1), (-1, 1), (-1, -1)].map(|t| centre(Point::from(t)));
+
+    let sensors: Vec<Sensor> = tiles
+        .into_iter()
+        .map(|tile| {
+            let location = centre(tile);
+            let beacon = if tile.x.abs() == 1 && tile.y.abs() == 1 {
+                // Reaches every point of the missing tile in its quadrant
+                // apart from the distress beacon itself
+                distress + Point::new(0, tile.y)
+            } else {
+                // A corner out of reach of the stretched sensors, so no
+                // sensor has a closer beacon than its own
+                Vector::ORTHOGONAL
+                    .map(|d| location + Point::new(d.x as i64, d.y as i64) * r)
+                    .into_iter()
+                    .max_by_key(|&corner| stretched.map(|s| s.manhattan(corner)).into_iter().min())
+                    .unwrap()
+            };
+            Sensor::new(location, beacon)
+        })
+        .collect();
+    serialize(&sensors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+    use proptest::{prop_assert_eq, proptest};
+
+    #[test]
+    fn part_one_example() {
+        let input = example!(15);
+        let sensors = Day15::parse(input).unwrap();
+        let params = SearchParams { row: 10, max: 20 };
+        assert_eq!(Day15::part_one(&sensors, &params).unwrap(), 26);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = example!(15);
+        let sensors = Day15::parse(input).unwrap();
+        let params = SearchParams { row: 10, max: 20 };
+        assert_eq!(Day15::part_two(&sensors, &params).unwrap(), 56000011);
+    }
+
+    #[test]
+    fn generated_input_has_one_gap() {
+        let params = SearchParams { row: 10, max: 20 };
+        for seed in 0..20 {
+            let input = generate_with(&mut Rng::new(seed), 10, &params);
+            let sensors = Day15::parse(&input).unwrap();
+            let gaps: Vec<Point<i64>> = (0..=params.max)
+                .flat_map(|y| (0..=params.max).map(move |x| Point::new(x, y)))
+                .filter(|&p| sensors.iter().all(|s| s.location.manhattan(p) > s.distance))
+                .collect();
+            assert_eq!(gaps.len(), 1, "seed {}:\n{}", seed, input);
+            assert_eq!(
+                Day15::part_two(&sensors, &params).unwrap(),
+                gaps[0].x * 4000000 + gaps[0].y
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn serialize_round_trips(seed: u64, size in 1..50usize) {
+            let input = generate(&mut Rng::new(seed), size);
+            prop_assert_eq!(serialize(&Day15::parse(&input).unwrap()), input);
+        }
+    }
+
+    #[test]
+    fn far_away_sensor_is_an_error() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=-9000000000000000000: closest beacon is at x=10, y=16
+";
+        let err = Day15::parse(input).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Day 15, line 2: Coordinate is out of range"));
+    }
+}
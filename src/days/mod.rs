@@ -0,0 +1,150 @@
+#[cfg(feature = "day-1")]
+pub mod day01;
+#[cfg(feature = "day-2")]
+pub mod day02;
+#[cfg(feature = "day-3")]
+pub mod day03;
+#[cfg(feature = "day-4")]
+pub mod day04;
+#[cfg(feature = "day-5")]
+pub mod day05;
+#[cfg(feature = "day-6")]
+pub mod day06;
+#[cfg(feature = "day-7")]
+pub mod day07;
+#[cfg(feature = "day-8")]
+pub mod day08;
+#[cfg(feature = "day-9")]
+pub mod day09;
+#[cfg(feature = "day-10")]
+pub mod day10;
+#[cfg(feature = "day-11")]
+pub mod day11;
+#[cfg(feature = "day-12")]
+pub mod day12;
+#[cfg(feature = "day-13")]
+pub mod day13;
+#[cfg(feature = "day-14")]
+pub mod day14;
+#[cfg(feature = "day-15")]
+pub mod day15;
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    generate::GenerateFn,
+    memory::{self, Usage},
+    AocError, Solution,
+};
+
+/// Answer to one part of a puzzle along with how long it took to find
+#[derive(Debug)]
+pub struct Answer {
+    pub text: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// Heap used to parse and solve, if built with `count-allocations`
+    pub memory: Option<Usage>,
+}
+
+/// Solves one part of a puzzle, returning the answer as text
+pub type PartFn = fn(&str) -> Result<Answer, AocError>;
+
+/// Parses the input then times `solve` on the result, measuring the heap
+/// used by both
+// Unused if the crate is built with no days enabled
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+fn timed<S: Solution, T: ToString>(
+    input: &str,
+    solve: impl FnOnce(&S::Input, &S::Params) -> Result<T, AocError>,
+) -> Result<Answer, AocError> {
+    let (answer, memory) = memory::measure(|| {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = solve(&parsed, &S::Params::default())?;
+        let solve_time = start.elapsed();
+        Ok::<_, AocError>((answer.to_string(), parse_time, solve_time))
+    });
+    let (text, parse_time, solve_time) = answer?;
+
+    Ok(Answer {
+        text,
+        parse_time,
+        solve_time,
+        memory,
+    })
+}
+
+pub struct Day {
+    pub number: u8,
+    pub part_one: PartFn,
+    pub part_two: PartFn,
+    /// Builds random inputs for benchmarks and tests, if the day has one
+    pub generate: Option<GenerateFn>,
+}
+
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            part_one: |input| timed::<S, _>(input, S::part_one),
+            part_two: |input| timed::<S, _>(input, S::part_two),
+            generate: None,
+        }
+    }
+
+    const fn with_generator(mut self, generate: GenerateFn) -> Self {
+        self.generate = Some(generate);
+        self
+    }
+
+    pub fn part(&self, part: u8) -> Option<PartFn> {
+        match part {
+            1 => Some(self.part_one),
+            2 => Some(self.part_two),
+            _ => None,
+        }
+    }
+}
+
+/// Every day enabled by a feature, in order
+pub const DAYS: &[Day] = &[
+    #[cfg(feature = "day-1")]
+    Day::new::<day01::Day01>().with_generator(day01::generate),
+    #[cfg(feature = "day-2")]
+    Day::new::<day02::Day02>().with_generator(day02::generate),
+    #[cfg(feature = "day-3")]
+    Day::new::<day03::Day03>().with_generator(day03::generate),
+    #[cfg(feature = "day-4")]
+    Day::new::<day04::Day04>().with_generator(day04::generate),
+    #[cfg(feature = "day-5")]
+    Day::new::<day05::Day05>().with_generator(day05::generate),
+    #[cfg(feature = "day-6")]
+    Day::new::<day06::Day06>().with_generator(day06::generate),
+    #[cfg(feature = "day-7")]
+    Day::new::<day07::Day07>().with_generator(day07::generate),
+    #[cfg(feature = "day-8")]
+    Day::new::<day08::Day08>().with_generator(day08::generate),
+    #[cfg(feature = "day-9")]
+    Day::new::<day09::Day09>().with_generator(day09::generate),
+    #[cfg(feature = "day-10")]
+    Day::new::<day10::Day10>().with_generator(day10::generate),
+    #[cfg(feature = "day-11")]
+    Day::new::<day11::Day11>().with_generator(day11::generate),
+    #[cfg(feature = "day-12")]
+    Day::new::<day12::Day12>().with_generator(day12::generate),
+    #[cfg(feature = "day-13")]
+    Day::new::<day13::Day13>().with_generator(day13::generate),
+    #[cfg(feature = "day-14")]
+    Day::new::<day14::Day14>().with_generator(day14::generate),
+    #[cfg(feature = "day-15")]
+    Day::new::<day15::Day15>().with_generator(day15::generate),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
@@ -0,0 +1,122 @@
+use std::{error::Error, fmt};
+
+use crate::input::InputError;
+#[cfg(feature = "visualize")]
+use crate::visualize::RecordError;
+
+pub enum AocError {
+    /// Puzzle input could not be read
+    Input(InputError),
+    /// Animation frames could not be written
+    #[cfg(feature = "visualize")]
+    Record(RecordError),
+    /// A line of the puzzle input is malformed
+    Parse {
+        day: u8,
+        line: usize,
+        text: String,
+        reason: String,
+    },
+    /// Input parsed but the puzzle has no answer for it
+    Solve { day: u8, reason: String },
+    /// Part has not been solved yet
+    Unsolved { day: u8, part: u8 },
+}
+
+impl AocError {
+    /// Error for the given line of the input, numbered from 1
+    pub fn parse(day: u8, line: usize, text: &str, reason: impl fmt::Display) -> Self {
+        Self::Parse {
+            day,
+            line,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Error for the line of `input` that `rest` starts on, where `rest` is
+    /// the unparsed remainder of `input`
+    pub fn parse_at(day: u8, input: &str, rest: &str, reason: impl fmt::Display) -> Self {
+        let offset = input.len().saturating_sub(rest.len());
+        let before = input.get(..offset).unwrap_or_default();
+        let start = before.rfind('\n').map_or(0, |i| i + 1);
+        let end = input
+            .get(offset..)
+            .unwrap_or_default()
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let line = before.matches('\n').count() + 1;
+        Self::parse(day, line, input.get(start..end).unwrap_or(rest), reason)
+    }
+
+    pub fn solve(day: u8, reason: impl fmt::Display) -> Self {
+        Self::Solve {
+            day,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Input(e) => write!(f, "{}", e),
+            #[cfg(feature = "visualize")]
+            AocError::Record(e) => write!(f, "{}", e),
+            AocError::Parse {
+                day,
+                line,
+                text,
+                reason,
+            } => write!(f, "Day {}, line {}: {} in {:?}", day, line, reason, text),
+            AocError::Solve { day, reason } => write!(f, "Day {}: {}", day, reason),
+            AocError::Unsolved { day, part } => {
+                write!(f, "Day {} part {} has not been solved", day, part)
+            }
+        }
+    }
+}
+
+// Errors returned from `main` are printed with `Debug`, so show the message
+// rather than the structure
+impl fmt::Debug for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Input(e) => Some(e),
+            #[cfg(feature = "visualize")]
+            AocError::Record(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for AocError {
+    fn from(e: InputError) -> Self {
+        AocError::Input(e)
+    }
+}
+
+#[cfg(feature = "visualize")]
+impl From<RecordError> for AocError {
+    fn from(e: RecordError) -> Self {
+        AocError::Record(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_at_finds_line() {
+        let input = "first\nsecond\nthird";
+        let err = AocError::parse_at(1, input, &input[9..], "bad");
+        assert_eq!(err.to_string(), "Day 1, line 2: bad in \"second\"");
+    }
+}
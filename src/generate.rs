@@ -0,0 +1,103 @@
+use crate::days;
+
+/// Builds a random puzzle input of roughly the given size
+pub type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// Small seedable random number generator (SplitMix64), so generated inputs
+/// are reproducible from their seed
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`, which must not be empty
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Uniform value in `low..=high`
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        let span = high.abs_diff(low) + 1;
+        low.wrapping_add(self.below(span) as i64)
+    }
+
+    /// Uniform index into a collection of length `len`
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `1 / n`
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    /// Random lowercase word with a length in `min..=max`
+    pub fn word(&mut self, min: usize, max: usize) -> String {
+        let len = self.between(min as i64, max as i64);
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+/// Random input for `day`, or `None` if the day has no generator
+pub fn input(day: u8, size: usize, seed: u64) -> Option<String> {
+    let generate = days::get(day)?.generate?;
+    Some(generate(&mut Rng::new(seed), size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AocError;
+
+    #[test]
+    fn rng_is_reproducible() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert!((0..100)
+            .map(|_| a.between(-3, 3))
+            .all(|n| (-3..=3).contains(&n)));
+    }
+
+    #[test]
+    fn generated_inputs_solve() {
+        // Newly scaffolded days have no generator until one is written
+        for day in days::DAYS.iter().filter(|day| day.generate.is_some()) {
+            for (size, seed) in [(1, 0), (5, 1), (40, 2)] {
+                let input = input(day.number, size, seed).expect("filtered to days with one");
+                for part in [1, 2] {
+                    match (day.part(part).unwrap())(&input) {
+                        Ok(_) | Err(AocError::Unsolved { .. }) => {}
+                        Err(e) => panic!("size {} seed {}: {}\n{}", size, seed, e, input),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{point::Vector, AocError};
+
+/// Rectangular grid of cells indexed by `(x, y)`, with `y` increasing down
+/// the rows
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one row per line, mapping each character to a cell
+    pub fn parse<E, F>(day: u8, input: &str, mut cell: F) -> Result<Self, AocError>
+    where
+        E: fmt::Display,
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        if width == 0 {
+            return Err(AocError::parse(day, 1, "", "Expected a row of the grid"));
+        }
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (index, line) in input.lines().enumerate() {
+            let error = |reason| AocError::parse(day, index + 1, line, reason);
+            if line.chars().count() != width {
+                return Err(error("Row has a different width".to_string()));
+            }
+            for c in line.chars() {
+                cells.push(cell(c).map_err(|e| error(e.to_string()))?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then(|| x + y * self.width)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index(x, y).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell along with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// Position of the first cell matching `predicate`, row by row
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    /// Moves `steps` times by `direction`, if that stays inside the grid
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        direction: Vector,
+        steps: usize,
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(direction.x.checked_mul(steps as isize)?)?;
+        let y = y.checked_add_signed(direction.y.checked_mul(steps as isize)?)?;
+        self.index(x, y).map(|_| (x, y))
+    }
+
+    /// Positions of the up to 4 cells sharing an edge with `(x, y)`
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Vector::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |d| self.offset((x, y), d, 1))
+    }
+
+    /// Positions of the up to 8 cells sharing an edge or corner with `(x, y)`
+    pub fn all_neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Vector::ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |d| self.offset((x, y), d, 1))
+    }
+
+    /// Cells of row `y` from left to right
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let start = (y * self.width).min(self.cells.len());
+        let end = (start + self.width).min(self.cells.len());
+        self.cells[start..end].iter()
+    }
+
+    /// Cells of column `x` from top to bottom
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let start = if x < self.width { x } else { self.cells.len() };
+        self.cells[start..].iter().step_by(self.width.max(1))
+    }
+
+    /// Cells reached by repeatedly stepping in `direction` from `(x, y)`, not
+    /// including `(x, y)` itself, until the edge of the grid
+    pub fn ray(&self, x: usize, y: usize, direction: Vector) -> impl Iterator<Item = &T> {
+        (1..)
+            .map_while(move |steps| self.offset((x, y), direction, steps))
+            .map(|(x, y)| &self[(x, y)])
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the grid", x, y))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(0, input, |c| c.to_digit(10).ok_or("Expected a digit")).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&6));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn ragged_rows_are_an_error() {
+        let err = Grid::parse(0, "12\n3\n", |c| c.to_digit(10).ok_or("")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 0, line 2: Row has a different width in \"3\""
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside() {
+        let grid = digits("123\n456\n789\n");
+        let corner: Vec<_> = grid.neighbours(0, 0).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.all_neighbours(1, 1).count(), 8);
+        assert_eq!(grid.all_neighbours(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn rows_columns_and_rays() {
+        let grid = digits("123\n456\n789\n");
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), vec![4, 5, 6]);
+        assert_eq!(
+            grid.column(2).rev().copied().collect::<Vec<_>>(),
+            vec![9, 6, 3]
+        );
+        assert_eq!(
+            grid.ray(0, 2, Vector::new(1, -1))
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![5, 3]
+        );
+        assert_eq!(grid.ray(0, 0, Vector::LEFT).count(), 0);
+    }
+}
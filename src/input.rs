@@ -0,0 +1,221 @@
+use std::{
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+
+use crate::AocError;
+
+#[derive(Debug)]
+pub struct InputError {
+    /// Where the input came from, if known
+    path: Option<PathBuf>,
+    source: io::Error,
+}
+
+impl InputError {
+    fn new(path: &Path, source: io::Error) -> Self {
+        Self {
+            path: Some(path.to_path_buf()),
+            source,
+        }
+    }
+
+    /// Error reading from a reader whose origin isn't known
+    pub fn unnamed(source: io::Error) -> Self {
+        Self { path: None, source }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.path {
+            Some(path) if is_stdin(path) => write!(f, "Unable to read stdin: {}", self.source),
+            Some(path) => write!(f, "Unable to read {}: {}", path.display(), self.source),
+            None => write!(f, "Unable to read input: {}", self.source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Conventional location of the puzzle input for a day
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("input/day-{}.txt", day))
+}
+
+/// Example input from the puzzle description, embedded at compile time so
+/// tests don't depend on the working directory. `example!(9)` is
+/// `input/day-9-example.txt`, and a day with several examples numbers the
+/// rest from 2, so `example!(9, 2)` is `input/day-9-example-2.txt`.
+#[macro_export]
+macro_rules! example {
+    ($day:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/input/day-",
+            $day,
+            "-example.txt"
+        ))
+    };
+    ($day:literal, $number:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/input/day-",
+            $day,
+            "-example-",
+            $number,
+            ".txt"
+        ))
+    };
+}
+
+fn is_stdin(path: &Path) -> bool {
+    path == Path::new("-")
+}
+
+/// Reads the whole input file into a string, or all of stdin if the path is
+/// `-`
+pub fn read_to_string<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+    if is_stdin(path) {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| InputError::new(path, e))?;
+        return Ok(input);
+    }
+    fs::read_to_string(path).map_err(|e| InputError::new(path, e))
+}
+
+/// Opens the input file for reading a line at a time, or stdin if the path
+/// is `-`
+pub fn open<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>, InputError> {
+    let path = path.as_ref();
+    if is_stdin(path) {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file = File::open(path).map_err(|e| InputError::new(path, e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Command line options for choosing the puzzle input
+#[derive(clap::Args)]
+pub struct InputArgs {
+    /// Puzzle input to use instead of input/day-N.txt, or - for stdin
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+}
+
+impl InputArgs {
+    /// Reads the chosen input, defaulting to the conventional path for `day`
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        match &self.input {
+            Some(path) => read_to_string(path),
+            None => read_to_string(default_path(day)),
+        }
+    }
+
+    /// Opens the chosen input without reading it all into memory
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        match &self.input {
+            Some(path) => open(path),
+            None => open(default_path(day)),
+        }
+    }
+}
+
+/// Solve one day of Advent of Code 2022
+#[derive(Parser)]
+struct DayCli {
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Reads the input chosen on the command line of a day binary
+pub fn from_args(day: u8) -> Result<String, InputError> {
+    DayCli::parse().input.read(day)
+}
+
+/// Parses each line of the input into groups separated by one or more blank
+/// lines, reporting the line number of any failure. The end of the input
+/// ends the last group whether or not a blank line comes first, and lines may
+/// end in `\n` or `\r\n`.
+pub fn parse_groups<T, E, F>(day: u8, input: &str, mut parse: F) -> Result<Vec<Vec<T>>, AocError>
+where
+    E: fmt::Display,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            if !group.is_empty() {
+                groups.push(std::mem::take(&mut group));
+            }
+            continue;
+        }
+        group.push(parse(line).map_err(|e| AocError::parse(day, index + 1, line, e))?);
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    Ok(groups)
+}
+
+/// Parses each line of the input, reporting the line number of any failure
+pub fn parse_lines<T, E, F>(day: u8, input: &str, mut parse: F) -> Result<Vec<T>, AocError>
+where
+    E: fmt::Display,
+    F: FnMut(&str) -> Result<T, E>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|e| AocError::parse(day, index + 1, line, e)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_groups_skips_runs_of_blank_lines() {
+        let groups = parse_groups(1, "\n\n1\n2\n\n\n\n3\n", str::parse::<u32>).unwrap();
+        assert_eq!(groups, vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn parse_groups_ends_last_group_at_end_of_input() {
+        let parse = |input| parse_groups(1, input, str::parse::<u32>).unwrap();
+        let expected = vec![vec![1, 2], vec![3], vec![4, 5]];
+        assert_eq!(parse("1\n2\n\n3\n\n4\n5\n\n"), expected);
+        assert_eq!(parse("1\n2\n\n3\n\n4\n5\n"), expected);
+        assert_eq!(parse("1\n2\n\n3\n\n4\n5"), expected);
+        assert_eq!(parse("1\r\n2\r\n\r\n3\r\n\r\n\r\n4\r\n5"), expected);
+    }
+
+    #[test]
+    fn parse_groups_reports_line() {
+        let err = parse_groups(1, "1\n\n2\nx\n", str::parse::<u32>).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 1, line 4: invalid digit found in string in \"x\""
+        );
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let err = read_to_string("input/does-not-exist.txt").unwrap_err();
+        assert!(err.to_string().contains("does-not-exist.txt"));
+    }
+}
@@ -0,0 +1,16 @@
+pub mod days;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod memory;
+pub mod parsing;
+pub mod point;
+pub mod pool;
+pub mod scaffold;
+pub mod solution;
+#[cfg(feature = "visualize")]
+pub mod visualize;
+
+pub use error::AocError;
+pub use solution::Solution;
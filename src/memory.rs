@@ -0,0 +1,150 @@
+/// Heap used while solving, counted by the allocator installed with the
+/// `count-allocations` feature
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Most bytes in use at once, above what was in use beforehand
+    pub peak: usize,
+    /// Number of allocations and reallocations
+    pub allocations: usize,
+    /// Total bytes asked for, including memory freed again
+    pub allocated: usize,
+}
+
+/// Runs `f`, along with the heap it used if allocations are being counted
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<Usage>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let start = counting::start();
+        let result = f();
+        (result, Some(counting::since(start)))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    (f(), None)
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use super::Usage;
+
+    /// Counts for the current thread, so parts solved at the same time on
+    /// the thread pool don't see each other's allocations
+    struct Counts {
+        current: Cell<usize>,
+        peak: Cell<usize>,
+        allocations: Cell<usize>,
+        allocated: Cell<usize>,
+    }
+
+    thread_local! {
+        // Const initialised with no destructor, so using it never allocates
+        static COUNTS: Counts = const {
+            Counts {
+                current: Cell::new(0),
+                peak: Cell::new(0),
+                allocations: Cell::new(0),
+                allocated: Cell::new(0),
+            }
+        };
+    }
+
+    fn record(freed: usize, allocated: usize) {
+        // Fails only while the thread is being torn down
+        let _ = COUNTS.try_with(|counts| {
+            // Memory can be freed on a different thread to the one that
+            // allocated it
+            let current = counts.current.get().saturating_sub(freed) + allocated;
+            counts.current.set(current);
+            counts.peak.set(counts.peak.get().max(current));
+            if allocated > 0 {
+                counts.allocations.set(counts.allocations.get() + 1);
+                counts.allocated.set(counts.allocated.get() + allocated);
+            }
+        });
+    }
+
+    struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(0, layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(0, layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(layout.size(), 0);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                record(layout.size(), new_size);
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    /// Counts on this thread when measuring started
+    pub struct Start {
+        current: usize,
+        allocations: usize,
+        allocated: usize,
+    }
+
+    pub fn start() -> Start {
+        COUNTS.with(|counts| {
+            counts.peak.set(counts.current.get());
+            Start {
+                current: counts.current.get(),
+                allocations: counts.allocations.get(),
+                allocated: counts.allocated.get(),
+            }
+        })
+    }
+
+    pub fn since(start: Start) -> Usage {
+        COUNTS.with(|counts| Usage {
+            peak: counts.peak.get().saturating_sub(start.current),
+            allocations: counts.allocations.get() - start.allocations,
+            allocated: counts.allocated.get() - start.allocated,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "count-allocations"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        let (_, usage) = measure(|| {
+            let small = vec![0u8; 1000];
+            drop(small);
+            let big = vec![0u8; 4000];
+            big.len()
+        });
+        let usage = usage.unwrap();
+        assert_eq!(usage.allocations, 2);
+        assert_eq!(usage.allocated, 5000);
+        assert_eq!(usage.peak, 4000);
+    }
+}
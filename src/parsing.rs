@@ -0,0 +1,146 @@
+use std::{fmt, str::FromStr};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, space0},
+    combinator::{map, map_res, opt, recognize},
+    error::Error,
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair, tuple},
+    IResult, Parser,
+};
+
+use crate::{point::Point, AocError};
+
+/// Number made of digits only
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Number made of digits with an optional leading minus sign
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// One or more lines each matched by `parser`, not including the final line
+/// ending
+pub fn lines_of<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(line_ending, parser)
+}
+
+/// One or more groups of lines each matched by `parser`, separated by blank
+/// lines
+pub fn blocks_of<'a, O, F>(parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    separated_list1(pair(line_ending, line_ending), parser)
+}
+
+/// Value following `label`, ignoring any indentation and the spaces after the
+/// label
+pub fn labelled<'a, O, F>(label: &'a str, parser: F) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    preceded(tuple((space0, tag(label), space0)), parser)
+}
+
+/// Point written as `x,y`
+pub fn point<T: FromStr>(input: &str) -> IResult<&str, Point<T>> {
+    map(separated_pair(signed, char(','), signed), |(x, y)| {
+        Point::new(x, y)
+    })(input)
+}
+
+/// Point written as `x=1, y=2`
+pub fn xy_point<T: FromStr>(input: &str) -> IResult<&str, Point<T>> {
+    let x = preceded(tag("x="), signed);
+    let y = preceded(tag("y="), signed);
+    map(separated_pair(x, tag(", "), y), |(x, y)| Point::new(x, y))(input)
+}
+
+/// Error pointing at the line and column of `input` that `rest` starts at
+fn error_at(day: u8, input: &str, rest: &str, reason: impl fmt::Display) -> AocError {
+    let offset = input.len().saturating_sub(rest.len());
+    let before = input.get(..offset).unwrap_or_default();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    let reason = format!("{} at column {}", reason, column);
+    AocError::parse_at(day, input, rest, reason)
+}
+
+/// Runs `parser` over the whole input, which may only have whitespace left
+/// over afterwards
+pub fn parse_all<'a, O, F>(day: u8, input: &'a str, mut parser: F) -> Result<O, AocError>
+where
+    F: Parser<&'a str, O, Error<&'a str>>,
+{
+    let (rest, output) = parser.parse(input).map_err(|e| match e {
+        nom::Err::Error(e) | nom::Err::Failure(e) => {
+            error_at(day, input, e.input, format!("expected {:?}", e.code))
+        }
+        nom::Err::Incomplete(_) => error_at(day, input, "", "unexpected end of input"),
+    })?;
+
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        return Err(error_at(day, input, rest, "Unexpected input"));
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(signed::<i32>("-12,"), Ok((",", -12)));
+        assert_eq!(unsigned::<u8>("255"), Ok(("", 255)));
+        assert!(unsigned::<u8>("256").is_err());
+        assert!(signed::<u32>("-1").is_err());
+    }
+
+    #[test]
+    fn points() {
+        assert_eq!(point::<usize>("498,4 ->"), Ok((" ->", Point::new(498, 4))));
+        assert_eq!(xy_point::<i64>("x=-2, y=15"), Ok(("", Point::new(-2, 15))));
+    }
+
+    #[test]
+    fn labelled_lines() {
+        let parser = lines_of(labelled("Value:", unsigned::<u32>));
+        assert_eq!(
+            parse_all(0, "Value: 1\n  Value:2\n", parser).unwrap(),
+            vec![1, 2]
+        );
+    }
+
+    #[test]
+    fn trailing_garbage_is_an_error() {
+        let err = parse_all(0, "1\n2\n3x\n", lines_of(unsigned::<u32>)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 0, line 3: Unexpected input at column 2 in \"3x\""
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_failure() {
+        let err = parse_all(0, "1,2\n3;4", lines_of(point::<u32>)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 0, line 2: Unexpected input at column 1 in \"3;4\""
+        );
+
+        let err = parse_all(0, "a", lines_of(point::<u32>)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Day 0, line 1: expected Digit at column 1 in \"a\""
+        );
+    }
+}
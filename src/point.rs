@@ -0,0 +1,221 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Position or offset on a 2D plane, with `y` increasing downwards to match
+/// the way puzzle inputs are laid out
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Offset between two points, such as a step in some direction
+pub type Vector = Point<isize>;
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {$(
+        impl Point<$t> {
+            pub const UP: Self = Self::new(0, -1);
+            pub const DOWN: Self = Self::new(0, 1);
+            pub const LEFT: Self = Self::new(-1, 0);
+            pub const RIGHT: Self = Self::new(1, 0);
+
+            /// Steps to the orthogonal neighbours, clockwise from up
+            pub const ORTHOGONAL: [Self; 4] = [Self::UP, Self::RIGHT, Self::DOWN, Self::LEFT];
+
+            /// Steps to the orthogonal and diagonal neighbours, clockwise
+            /// from up
+            pub const ALL_DIRECTIONS: [Self; 8] = [
+                Self::UP,
+                Self::new(1, -1),
+                Self::RIGHT,
+                Self::new(1, 1),
+                Self::DOWN,
+                Self::new(-1, 1),
+                Self::LEFT,
+                Self::new(-1, -1),
+            ];
+
+            pub fn manhattan(self, other: Self) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs()
+            }
+
+            pub fn chebyshev(self, other: Self) -> $t {
+                (self.x - other.x).abs().max((self.y - other.y).abs())
+            }
+
+            /// Vector with each component clamped to -1, 0 or 1
+            pub fn signum(self) -> Self {
+                Self::new(self.x.signum(), self.y.signum())
+            }
+        }
+    )*};
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {$(
+        impl Point<$t> {
+            pub fn manhattan(self, other: Self) -> $t {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+            }
+
+            pub fn chebyshev(self, other: Self) -> $t {
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+            }
+        }
+    )*};
+}
+
+signed!(i32, i64, isize);
+unsigned!(u32, u64, usize);
+
+impl<T: Copy + Into<f64>> Point<T> {
+    pub fn euclidean(self, other: Self) -> f64 {
+        let dx = self.x.into() - other.x.into();
+        let dy = self.y.into() - other.y.into();
+        dx.hypot(dy)
+    }
+}
+
+impl Point<usize> {
+    /// Moves by `vector`, unless that would go below zero
+    pub fn offset(self, vector: Vector) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(vector.x)?,
+            self.y.checked_add_signed(vector.y)?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Smallest rectangle containing a set of points, inclusive of its edges
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundingBox<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Copy + Ord> BoundingBox<T> {
+    pub fn new(min: Point<T>, max: Point<T>) -> Self {
+        Self { min, max }
+    }
+
+    /// Bounding box of `points`, or `None` if there aren't any
+    pub fn of(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), Self::including))
+    }
+
+    /// Smallest bounding box containing both this one and `point`
+    pub fn including(self, point: Point<T>) -> Self {
+        Self::new(
+            Point::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            Point::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        )
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a = Point::new(1i64, -2);
+        let b = Point::new(4, 2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point::new(1u32, 2).euclidean(Point::new(4, 6)), 5.0);
+        assert_eq!(Point::new(3usize, 0).manhattan(Point::new(0, 2)), 5);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut p = Point::new(2, 3) + Vector::RIGHT * 2 - Vector::UP;
+        assert_eq!(p, Point::new(4, 4));
+        p -= Point::new(5, 5);
+        assert_eq!(p.signum(), Point::new(-1, -1));
+        assert_eq!(Point::new(0usize, 1).offset(Vector::LEFT), None);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point::new(3, 1), Point::new(-1, 4), Point::new(2, -2)];
+        let bounds = BoundingBox::of(points).unwrap();
+        assert_eq!(bounds.min, Point::new(-1, -2));
+        assert_eq!(bounds.max, Point::new(3, 4));
+        assert!(bounds.contains(Point::new(0, 0)));
+        assert!(!bounds.contains(Point::new(4, 0)));
+        assert_eq!(BoundingBox::<i32>::of([]), None);
+    }
+}
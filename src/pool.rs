@@ -0,0 +1,77 @@
+use std::{
+    collections::BTreeMap,
+    sync::{mpsc, Mutex},
+    thread,
+};
+
+/// Runs `work` on every item using up to `jobs` threads, passing the results
+/// to `emit` in the same order as the items as soon as each is ready
+pub fn map_ordered<T, R>(
+    items: Vec<T>,
+    jobs: usize,
+    work: impl Fn(T) -> R + Sync,
+    mut emit: impl FnMut(R),
+) where
+    T: Send,
+    R: Send,
+{
+    let jobs = jobs.clamp(1, items.len().max(1));
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs {
+            let sender = sender.clone();
+            let (queue, work) = (&queue, &work);
+            scope.spawn(move || loop {
+                // Take the lock only long enough to claim the next item
+                let next = queue.lock().expect("no worker panics holding it").next();
+                let Some((index, item)) = next else {
+                    return;
+                };
+                if sender.send((index, work(item))).is_err() {
+                    return;
+                }
+            });
+        }
+        drop(sender);
+
+        // Hold on to results that finish early until those before them are in
+        let mut waiting = BTreeMap::new();
+        let mut next = 0;
+        for (index, result) in receiver {
+            waiting.insert(index, result);
+            while let Some(result) = waiting.remove(&next) {
+                emit(result);
+                next += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn results_keep_their_order() {
+        let mut results = Vec::new();
+        // Earlier items take longer, so finish out of order
+        map_ordered(
+            (0..8u64).collect(),
+            4,
+            |i| {
+                thread::sleep(Duration::from_millis(40 - 5 * i));
+                i * i
+            },
+            |r| results.push(r),
+        );
+        assert_eq!(results, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+    }
+
+    #[test]
+    fn no_items_is_fine() {
+        map_ordered(Vec::<u8>::new(), 4, |i| i, |_| panic!("nothing to emit"));
+    }
+}
@@ -0,0 +1,325 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+const MODULE_TEMPLATE: &str = r#"use crate::{AocError, Solution};
+
+pub struct DayNN;
+
+impl Solution for DayNN {
+    const DAY: u8 = DAY_NUMBER;
+    type Input = Vec<String>;
+    type Params = ();
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>, AocError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(_lines: &Vec<String>, _: &()) -> Result<u64, AocError> {
+        Err(AocError::Unsolved {
+            day: Self::DAY,
+            part: 1,
+        })
+    }
+
+    fn part_two(_lines: &Vec<String>, _: &()) -> Result<u64, AocError> {
+        Err(AocError::Unsolved {
+            day: Self::DAY,
+            part: 2,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::example;
+
+    #[test]
+    fn part_one_example() {
+        // Fill in from the puzzle description
+        let expected: Option<u64> = None;
+        let answer = DayNN::part_one(&DayNN::parse(example!(DAY_NUMBER)).unwrap(), &()).unwrap();
+        assert_eq!(Some(answer), expected);
+    }
+
+    #[test]
+    fn part_two_example() {
+        // Fill in from the puzzle description
+        let expected: Option<u64> = None;
+        let answer = DayNN::part_two(&DayNN::parse(example!(DAY_NUMBER)).unwrap(), &()).unwrap();
+        assert_eq!(Some(answer), expected);
+    }
+}
+"#;
+
+const BIN_TEMPLATE: &str = r#"use advent_22::{days::dayNN::DayNN, input, AocError, Solution};
+
+fn main() -> Result<(), AocError> {
+    let input = input::from_args(DayNN::DAY)?;
+    let parsed = DayNN::parse(&input)?;
+    println!("Part one answer is: {}", DayNN::part_one(&parsed, &())?);
+    println!("Part two answer is: {}", DayNN::part_two(&parsed, &())?);
+    Ok(())
+}
+"#;
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("DayNN", &format!("Day{:02}", day))
+        .replace("dayNN", &format!("day{:02}", day))
+        .replace("DAY_NUMBER", &day.to_string())
+}
+
+/// First number in `line`, which is the day for every line `register` and
+/// `register_feature` look at
+fn day_number(line: &str) -> Option<u32> {
+    let start = line.find(|c: char| c.is_ascii_digit())?;
+    let digits = line[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(&line[start..], |end| &line[start..start + end]);
+    digits.parse().ok()
+}
+
+/// Inserts `group` among the groups of lines in `file` whose line `lead`
+/// lines in starts with `prefix`, keeping them sorted by day
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    file: &str,
+    prefix: &str,
+    group: &[String],
+    lead: usize,
+) -> io::Result<()> {
+    let line = &group[lead];
+    let run: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with(prefix))
+        .collect();
+    let Some(&last) = run.last() else {
+        let reason = format!("No lines starting with {:?} in {}", prefix, file);
+        return Err(io::Error::new(io::ErrorKind::InvalidData, reason));
+    };
+    if run.iter().any(|&i| lines[i] == *line) {
+        let reason = format!("{:?} is already in {}", line.trim(), file);
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, reason));
+    }
+
+    let index = run
+        .iter()
+        .find(|&&i| day_number(&lines[i]) > day_number(line))
+        .map_or(last + group.len() - lead, |&i| i - lead);
+    lines.splice(index..index, group.iter().cloned());
+    Ok(())
+}
+
+/// Adds the module for `day` to the contents of `days/mod.rs` and registers
+/// it in `DAYS`, both behind the day's feature
+pub fn register(mod_rs: &str, day: u8) -> io::Result<String> {
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+    let file = "days/mod.rs";
+    let module = [
+        fill("#[cfg(feature = \"day-DAY_NUMBER\")]", day),
+        fill("pub mod dayNN;", day),
+    ];
+    insert_sorted(&mut lines, file, "pub mod day", &module, 1)?;
+    let entry = [
+        fill("    #[cfg(feature = \"day-DAY_NUMBER\")]", day),
+        fill("    Day::new::<dayNN::DayNN>(),", day),
+    ];
+    insert_sorted(&mut lines, file, "    Day::new::<day", &entry, 1)?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the feature for `day` to the contents of `Cargo.toml`, includes it
+/// in `full`, and makes the day's binary require it
+pub fn register_feature(manifest: &str, day: u8) -> io::Result<String> {
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let file = "Cargo.toml";
+    let full = [fill("    \"day-DAY_NUMBER\",", day)];
+    insert_sorted(&mut lines, file, "    \"day-", &full, 0)?;
+    let feature = [fill("day-DAY_NUMBER = []", day)];
+    insert_sorted(&mut lines, file, "day-", &feature, 0)?;
+    let bin = [
+        String::new(),
+        "[[bin]]".to_string(),
+        fill("name = \"day-DAY_NUMBER\"", day),
+        fill("required-features = [\"day-DAY_NUMBER\"]", day),
+    ];
+    insert_sorted(&mut lines, file, "name = \"day-", &bin, 2)?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Writes `contents` to a new file, failing rather than overwriting one
+fn create(path: &Path, contents: &str) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    file.write_all(contents.as_bytes())
+}
+
+/// Generates the solution module, binary and input files for `day` in the
+/// crate at `root`, returning the paths written
+pub fn new_day(root: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let mod_rs = root.join("src/days/mod.rs");
+    let registered = register(&fs::read_to_string(&mod_rs)?, day)?;
+    let manifest = root.join("Cargo.toml");
+    let featured = register_feature(&fs::read_to_string(&manifest)?, day)?;
+
+    let files = [
+        (
+            root.join(format!("src/days/day{:02}.rs", day)),
+            fill(MODULE_TEMPLATE, day),
+        ),
+        (
+            root.join(format!("src/bin/day-{}.rs", day)),
+            fill(BIN_TEMPLATE, day),
+        ),
+        (root.join(format!("input/day-{}.txt", day)), String::new()),
+        (
+            root.join(format!("input/day-{}-example.txt", day)),
+            String::new(),
+        ),
+    ];
+
+    // Check first so a clash doesn't leave a half generated day behind
+    for (path, _) in &files {
+        if path.exists() {
+            let reason = format!("{} already exists", path.display());
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, reason));
+        }
+    }
+    for (path, contents) in &files {
+        create(path, contents)?;
+    }
+    fs::write(&mod_rs, registered)?;
+    fs::write(&manifest, featured)?;
+
+    let mut written: Vec<PathBuf> = files.into_iter().map(|(path, _)| path).collect();
+    written.push(mod_rs);
+    written.push(manifest);
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = r#"#[cfg(feature = "day-1")]
+pub mod day01;
+#[cfg(feature = "day-3")]
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    #[cfg(feature = "day-1")]
+    Day::new::<day01::Day01>(),
+    #[cfg(feature = "day-3")]
+    Day::new::<day03::Day03>(),
+];
+"#;
+
+    const MANIFEST: &str = r#"[features]
+default = ["full"]
+full = [
+    "day-1",
+    "day-3",
+]
+day-1 = []
+day-3 = []
+
+[[bin]]
+name = "day-1"
+required-features = ["day-1"]
+
+[[bin]]
+name = "day-3"
+required-features = ["day-3"]
+
+[[bench]]
+name = "days"
+"#;
+
+    #[test]
+    fn register_keeps_days_sorted() {
+        let registered = register(MOD_RS, 2).unwrap();
+        assert_eq!(
+            registered,
+            r#"#[cfg(feature = "day-1")]
+pub mod day01;
+#[cfg(feature = "day-2")]
+pub mod day02;
+#[cfg(feature = "day-3")]
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    #[cfg(feature = "day-1")]
+    Day::new::<day01::Day01>(),
+    #[cfg(feature = "day-2")]
+    Day::new::<day02::Day02>(),
+    #[cfg(feature = "day-3")]
+    Day::new::<day03::Day03>(),
+];
+"#
+        );
+        let registered = register(&registered, 16).unwrap();
+        assert!(registered.contains("\"day-16\")]\n    Day::new::<day16::Day16>(),\n];"));
+    }
+
+    #[test]
+    fn binary_prints_answers_like_existing_days() {
+        let existing = include_str!("bin/day-11.rs");
+        for part in ["one", "two"] {
+            let line = format!("println!(\"Part {} answer is: {{}}\"", part);
+            assert!(BIN_TEMPLATE.contains(&line), "{}", line);
+            assert!(existing.contains(&line), "{}", line);
+        }
+    }
+
+    #[test]
+    fn register_rejects_existing_day() {
+        let err = register(MOD_RS, 3).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    }
+
+    #[test]
+    fn register_feature_adds_feature_and_binary() {
+        let registered = register_feature(MANIFEST, 2).unwrap();
+        assert_eq!(
+            registered,
+            r#"[features]
+default = ["full"]
+full = [
+    "day-1",
+    "day-2",
+    "day-3",
+]
+day-1 = []
+day-2 = []
+day-3 = []
+
+[[bin]]
+name = "day-1"
+required-features = ["day-1"]
+
+[[bin]]
+name = "day-2"
+required-features = ["day-2"]
+
+[[bin]]
+name = "day-3"
+required-features = ["day-3"]
+
+[[bench]]
+name = "days"
+"#
+        );
+        // Days sort by number rather than as text
+        let registered = register_feature(&registered, 16).unwrap();
+        assert!(registered.contains("\"day-3\",\n    \"day-16\",\n]"));
+        assert!(registered.contains("[\"day-16\"]\n\n[[bench]]"));
+    }
+}
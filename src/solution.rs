@@ -0,0 +1,22 @@
+use std::fmt;
+
+use crate::AocError;
+
+pub trait Solution {
+    /// Puzzle day, used to locate the default input
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input shared by both parts
+    type Input;
+
+    /// Values that differ between the examples and the real puzzle, the
+    /// default being the values for the real puzzle
+    type Params: Default;
+
+    type PartOne: fmt::Display;
+    type PartTwo: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+    fn part_one(input: &Self::Input, params: &Self::Params) -> Result<Self::PartOne, AocError>;
+    fn part_two(input: &Self::Input, params: &Self::Params) -> Result<Self::PartTwo, AocError>;
+}
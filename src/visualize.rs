@@ -0,0 +1,423 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    error::Error,
+    fmt,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use clap::ValueEnum;
+
+use crate::grid::Grid;
+
+/// Colour of one cell of a frame
+pub type Rgb = [u8; 3];
+
+/// Simulation state that can be drawn as one frame of an animation
+pub trait Visualize {
+    fn frame(&self) -> Grid<Rgb>;
+}
+
+/// Mixes `from` and `to`, with `amount` of 0 giving `from` and 1 giving `to`
+pub fn blend(from: Rgb, to: Rgb, amount: f64) -> Rgb {
+    let amount = amount.clamp(0.0, 1.0);
+    [0, 1, 2].map(|i| (from[i] as f64 + (to[i] as f64 - from[i] as f64) * amount).round() as u8)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Animate in the terminal with 24-bit colour
+    Ansi,
+    /// Directory of numbered PPM images
+    Ppm,
+    /// Directory of numbered PNG images
+    Png,
+    /// Animated GIF
+    Gif,
+}
+
+/// Command line options for recording a simulation
+#[derive(clap::Args)]
+pub struct VisualizeArgs {
+    /// Record the simulation as an animation in this format
+    #[arg(long, value_enum)]
+    pub visualize: Option<Format>,
+
+    /// Directory to write image frames to, or the GIF file to write
+    #[arg(long, default_value = "frames")]
+    pub frames: PathBuf,
+
+    /// Only keep every Nth frame
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    pub every: u64,
+
+    /// Frames per second to play the animation at
+    #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u64).range(1..=100))]
+    pub fps: u64,
+
+    /// Pixels across each cell in image frames
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..=32))]
+    pub scale: u64,
+}
+
+impl VisualizeArgs {
+    /// Recorder for the chosen format, or `None` if `--visualize` wasn't
+    /// given
+    pub fn recorder(&self) -> Result<Option<Recorder>, RecordError> {
+        let Some(format) = self.visualize else {
+            return Ok(None);
+        };
+        let recorder = Recorder::new(format, &self.frames)?
+            .every(self.every as usize)
+            .fps(self.fps as u16)
+            .scale(self.scale as usize);
+        Ok(Some(recorder))
+    }
+}
+
+#[derive(Debug)]
+pub struct RecordError {
+    path: PathBuf,
+    source: io::Error,
+}
+
+impl RecordError {
+    fn new(path: &Path, source: impl Into<io::Error>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            source: source.into(),
+        }
+    }
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unable to write {}: {}",
+            self.path.display(),
+            self.source
+        )
+    }
+}
+
+impl Error for RecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Terminal with the screen cleared and the cursor hidden until it's dropped,
+/// so the cursor comes back however recording ends
+struct Terminal<W: Write>(W);
+
+impl<W: Write> Terminal<W> {
+    fn new(mut out: W) -> io::Result<Self> {
+        write!(out, "\x1b[2J\x1b[?25l")?;
+        Ok(Self(out))
+    }
+}
+
+impl<W: Write> Drop for Terminal<W> {
+    fn drop(&mut self) {
+        // Nowhere to report a failure to while unwinding from another error
+        let _ = write!(self.0, "\x1b[?25h").and_then(|_| self.0.flush());
+    }
+}
+
+enum Sink {
+    Ansi(Terminal<io::Stdout>),
+    Images { format: Format, dir: PathBuf },
+    Gif(Option<gif::Encoder<BufWriter<File>>>),
+}
+
+/// Writes frames of a simulation out as an animation
+pub struct Recorder {
+    sink: Sink,
+    path: PathBuf,
+    every: usize,
+    fps: u16,
+    scale: usize,
+    seen: usize,
+    written: usize,
+}
+
+impl Recorder {
+    /// Recorder writing to `path`, which is a directory for PPM and PNG
+    /// frames, a file for a GIF, and ignored for the terminal
+    pub fn new(format: Format, path: impl AsRef<Path>) -> Result<Self, RecordError> {
+        let path = path.as_ref();
+        let sink = match format {
+            Format::Ansi => {
+                let terminal =
+                    Terminal::new(io::stdout()).map_err(|e| RecordError::new(path, e))?;
+                Sink::Ansi(terminal)
+            }
+            Format::Ppm | Format::Png => {
+                fs::create_dir_all(path).map_err(|e| RecordError::new(path, e))?;
+                Sink::Images {
+                    format,
+                    dir: path.to_path_buf(),
+                }
+            }
+            // The encoder needs the size of the frames, so is created with
+            // the first one
+            Format::Gif => Sink::Gif(None),
+        };
+        Ok(Self {
+            sink,
+            path: path.to_path_buf(),
+            every: 1,
+            fps: 30,
+            scale: 4,
+            seen: 0,
+            written: 0,
+        })
+    }
+
+    /// Only keeps every `every`th frame recorded
+    pub fn every(mut self, every: usize) -> Self {
+        self.every = every.max(1);
+        self
+    }
+
+    pub fn fps(mut self, fps: u16) -> Self {
+        self.fps = fps.clamp(1, 100);
+        self
+    }
+
+    /// Draws each cell as a `scale` by `scale` square in image frames
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// Number of frames written so far
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Draws the current state of the simulation as the next frame
+    pub fn record(&mut self, state: &impl Visualize) -> Result<(), RecordError> {
+        let keep = self.seen.is_multiple_of(self.every);
+        self.seen += 1;
+        if !keep {
+            return Ok(());
+        }
+        self.write(&state.frame())
+    }
+
+    fn write(&mut self, frame: &Grid<Rgb>) -> Result<(), RecordError> {
+        let error = |e| RecordError::new(&self.path, e);
+        match &mut self.sink {
+            Sink::Ansi(terminal) => {
+                let mut out = terminal.0.lock();
+                out.write_all(ansi(frame).as_bytes()).map_err(error)?;
+                out.flush().map_err(error)?;
+                thread::sleep(Duration::from_secs(1) / self.fps as u32);
+            }
+            Sink::Images { format, dir } => {
+                let extension = if *format == Format::Ppm { "ppm" } else { "png" };
+                let path = dir.join(format!("frame-{:05}.{}", self.written, extension));
+                let (width, height, pixels) = scaled(frame, self.scale);
+                let file = File::create(&path).map_err(|e| RecordError::new(&path, e))?;
+                let mut file = BufWriter::new(file);
+                let written = if *format == Format::Ppm {
+                    write!(file, "P6\n{} {}\n255\n", width, height)
+                        .and_then(|_| file.write_all(&pixels))
+                        .and_then(|_| file.flush())
+                } else {
+                    write_png(file, width, height, &pixels)
+                };
+                written.map_err(|e| RecordError::new(&path, e))?;
+            }
+            Sink::Gif(encoder) => {
+                let (width, height, pixels) = scaled(frame, self.scale);
+                let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "frame is too big");
+                let width = u16::try_from(width).map_err(|_| error(too_big()))?;
+                let height = u16::try_from(height).map_err(|_| error(too_big()))?;
+
+                if encoder.is_none() {
+                    let file = File::create(&self.path).map_err(error)?;
+                    let mut new = gif::Encoder::new(BufWriter::new(file), width, height, &[])
+                        .map_err(|e| error(gif_error(e)))?;
+                    new.set_repeat(gif::Repeat::Infinite)
+                        .map_err(|e| error(gif_error(e)))?;
+                    *encoder = Some(new);
+                }
+                let encoder = encoder.as_mut().expect("created above");
+
+                let (palette, buffer) = indexed(&pixels);
+                let frame = gif::Frame {
+                    width,
+                    height,
+                    delay: (100 / self.fps).max(1),
+                    palette: Some(palette),
+                    buffer: Cow::Owned(buffer),
+                    ..gif::Frame::default()
+                };
+                encoder
+                    .write_frame(&frame)
+                    .map_err(|e| error(gif_error(e)))?;
+            }
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    /// Flushes anything still buffered, leaving the terminal as it was found
+    pub fn finish(self) -> Result<(), RecordError> {
+        match self.sink {
+            // Dropping the terminal shows the cursor again
+            Sink::Ansi(_) => Ok(()),
+            Sink::Images { .. } => Ok(()),
+            Sink::Gif(encoder) => match encoder {
+                Some(encoder) => encoder
+                    .into_inner()
+                    .and_then(|mut file| file.flush())
+                    .map_err(|e| RecordError::new(&self.path, e)),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+/// Frame drawn with half blocks, so each character shows two rows of cells
+fn ansi(frame: &Grid<Rgb>) -> String {
+    let mut out = String::from("\x1b[H");
+    for y in (0..frame.height()).step_by(2) {
+        for x in 0..frame.width() {
+            let [r, g, b] = frame[(x, y)];
+            let [br, bg, bb] = frame.get(x, y + 1).copied().unwrap_or_default();
+            out += &format!(
+                "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+                r, g, b, br, bg, bb
+            );
+        }
+        out += "\x1b[0m\n";
+    }
+    out
+}
+
+/// Frame as RGB bytes with each cell blown up to `scale` pixels square,
+/// returning the width and height in pixels
+fn scaled(frame: &Grid<Rgb>, scale: usize) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (frame.width() * scale, frame.height() * scale);
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            pixels.extend_from_slice(&frame[(x / scale, y / scale)]);
+        }
+    }
+    (width, height, pixels)
+}
+
+fn write_png(file: BufWriter<File>, width: usize, height: usize, pixels: &[u8]) -> io::Result<()> {
+    let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "frame is too big");
+    let width = u32::try_from(width).map_err(|_| too_big())?;
+    let height = u32::try_from(height).map_err(|_| too_big())?;
+    let mut encoder = png::Encoder::new(file, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(pixels)?;
+    writer.finish()?;
+    Ok(())
+}
+
+fn gif_error(e: gif::EncodingError) -> io::Error {
+    match e {
+        gif::EncodingError::Io(e) => e,
+        gif::EncodingError::Format(e) => io::Error::new(io::ErrorKind::InvalidData, e),
+    }
+}
+
+/// Palette and indexed pixels for a GIF frame. Frames with more than 256
+/// colours are reduced to 3 bits of red and green and 2 of blue first.
+fn indexed(pixels: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let mut colours: Vec<Rgb> = pixels.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect();
+    let distinct = colours
+        .iter()
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    if distinct > 256 {
+        for colour in colours.iter_mut() {
+            *colour = [colour[0] & 0xe0, colour[1] & 0xe0, colour[2] & 0xc0];
+        }
+    }
+
+    let mut palette = Vec::new();
+    let mut indices: HashMap<Rgb, u8> = HashMap::new();
+    let buffer = colours
+        .into_iter()
+        .map(|colour| {
+            *indices.entry(colour).or_insert_with(|| {
+                palette.extend_from_slice(&colour);
+                (palette.len() / 3 - 1) as u8
+            })
+        })
+        .collect();
+    (palette, buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Checkerboard;
+
+    impl Visualize for Checkerboard {
+        fn frame(&self) -> Grid<Rgb> {
+            let mut grid = Grid::new(2, 2, [0, 0, 0]);
+            grid[(0, 0)] = [255, 255, 255];
+            grid[(1, 1)] = [255, 255, 255];
+            grid
+        }
+    }
+
+    #[test]
+    fn frames_scale_up() {
+        let (width, height, pixels) = scaled(&Checkerboard.frame(), 2);
+        assert_eq!((width, height), (4, 4));
+        assert_eq!(
+            &pixels[..12],
+            &[255; 6].iter().chain(&[0; 6]).copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn terminal_cursor_comes_back_when_dropped() {
+        let mut out = Vec::new();
+        {
+            let _terminal = Terminal::new(&mut out).unwrap();
+        }
+        assert!(out.ends_with(b"\x1b[?25h"));
+    }
+
+    #[test]
+    fn gif_frames_use_a_palette() {
+        let (_, _, pixels) = scaled(&Checkerboard.frame(), 1);
+        let (palette, buffer) = indexed(&pixels);
+        assert_eq!(palette, vec![255, 255, 255, 0, 0, 0]);
+        assert_eq!(buffer, vec![0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn records_every_nth_frame_to_a_directory() {
+        let dir = std::env::temp_dir().join(format!("advent-22-frames-{}", std::process::id()));
+        let mut recorder = Recorder::new(Format::Ppm, &dir).unwrap().every(2);
+        for _ in 0..5 {
+            recorder.record(&Checkerboard).unwrap();
+        }
+        assert_eq!(recorder.written(), 3);
+        recorder.finish().unwrap();
+
+        let frame = fs::read(dir.join("frame-00002.ppm")).unwrap();
+        assert!(frame.starts_with(b"P6\n8 8\n255\n"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use advent_22::{days, input, AocError};
+use toml::{Table, Value};
+
+fn path(relative: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(relative)
+}
+
+/// Answer as text, ignoring surrounding blank lines and trailing whitespace
+fn normalise(answer: &str) -> String {
+    let lines: Vec<&str> = answer.lines().map(str::trim_end).collect();
+    lines.join("\n").trim_matches('\n').to_string()
+}
+
+fn expected_answers(day: u8) -> Table {
+    let registry: Table = fs::read_to_string(path("answers.toml"))
+        .expect("answers.toml should exist")
+        .parse()
+        .expect("answers.toml should be valid TOML");
+
+    match registry.get(&format!("day-{}", day)) {
+        Some(Value::Table(answers)) => answers.clone(),
+        Some(_) => panic!("day-{} should be a table", day),
+        None => Table::new(),
+    }
+}
+
+fn check(day: u8) {
+    let info = days::get(day).expect("day should be registered");
+    let input = input::read_to_string(path(input::default_path(day))).unwrap();
+    let answers = expected_answers(day);
+
+    for (part, key) in [(1, "part-one"), (2, "part-two")] {
+        let solve = info.part(part).unwrap();
+        let expected = match answers.get(key) {
+            Some(Value::String(s)) => s.clone(),
+            Some(Value::Integer(i)) => i.to_string(),
+            Some(other) => panic!("day-{}.{} has unexpected value {}", day, key, other),
+            None => {
+                // Only unsolved parts may be missing from the registry
+                match solve(&input) {
+                    Err(AocError::Unsolved { .. }) => continue,
+                    Ok(answer) => {
+                        panic!("day-{}.{} = {:?} is missing", day, key, answer.text)
+                    }
+                    Err(e) => panic!("{}", e),
+                }
+            }
+        };
+
+        let answer = solve(&input).unwrap_or_else(|e| panic!("{}", e));
+        assert_eq!(
+            normalise(&answer.text),
+            normalise(&expected),
+            "day {} part {}",
+            day,
+            part
+        );
+    }
+}
+
+#[test]
+fn registry_only_has_known_days() {
+    let registry: Table = fs::read_to_string(path("answers.toml"))
+        .unwrap()
+        .parse()
+        .unwrap();
+    for key in registry.keys() {
+        let day = key
+            .strip_prefix("day-")
+            .and_then(|d| d.parse().ok())
+            .unwrap_or_else(|| panic!("unexpected table {}", key));
+        assert!(days::get(day).is_some(), "day {} isn't registered", day);
+    }
+}
+
+#[test]
+fn day_1() {
+    check(1);
+}
+
+#[test]
+fn day_2() {
+    check(2);
+}
+
+#[test]
+fn day_3() {
+    check(3);
+}
+
+#[test]
+fn day_4() {
+    check(4);
+}
+
+#[test]
+fn day_5() {
+    check(5);
+}
+
+#[test]
+fn day_6() {
+    check(6);
+}
+
+#[test]
+fn day_7() {
+    check(7);
+}
+
+#[test]
+fn day_8() {
+    check(8);
+}
+
+#[test]
+fn day_9() {
+    check(9);
+}
+
+#[test]
+fn day_10() {
+    check(10);
+}
+
+#[test]
+fn day_11() {
+    check(11);
+}
+
+#[test]
+fn day_12() {
+    check(12);
+}
+
+#[test]
+fn day_13() {
+    check(13);
+}
+
+#[test]
+fn day_14() {
+    check(14);
+}
+
+#[test]
+fn day_15() {
+    check(15);
+}